@@ -0,0 +1,284 @@
+//! Command-line tool for ISO 10962 Classification of Financial Instruments codes.
+
+use clap::{Parser, Subcommand};
+use iso10962_types::{CfiPattern, Code};
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+/// Decode, validate, and list ISO 10962 CFI codes.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Cli {
+    /// The command to run.
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// The available commands.
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Print the category, group, and attributes of the given codes.
+    Decode {
+        /// The codes to decode.
+        #[arg(required = true)]
+        codes: Vec<String>,
+    },
+
+    /// Check codes, one per line, and report the lines which are invalid.
+    Validate {
+        /// The file to read codes from, standard input if omitted.
+        file: Option<PathBuf>,
+    },
+
+    /// List every valid code which matches the given pattern.
+    List {
+        /// The pattern to match, e.g. `ES****` or `O[CP]*C**`.
+        pattern: String,
+    },
+}
+
+/// Write the description of each code to `out`, and the reason for each invalid code to `err`.
+///
+/// Returns whether every code was valid.
+fn decode(codes: &[String], out: &mut impl Write, err: &mut impl Write) -> io::Result<bool> {
+    let mut valid = true;
+    for code in codes {
+        match Code::from_bytes(code.as_bytes()) {
+            Ok(parsed) => {
+                writeln!(out, "{parsed}")?;
+                let bytes = parsed.to_bytes();
+                for (label, byte) in parsed.describe().iter().zip(bytes) {
+                    writeln!(
+                        out,
+                        "  {:<16}{}  {}",
+                        label.field,
+                        char::from(byte),
+                        label.description
+                    )?;
+                }
+            }
+            Err(error) => {
+                writeln!(err, "{code}: {error}")?;
+                valid = false;
+            }
+        }
+    }
+
+    Ok(valid)
+}
+
+/// Read codes from `input`, one per line, and write the reason for each invalid line to `out`.
+///
+/// Blank lines and surrounding ASCII whitespace are ignored, and lines which are not UTF-8 are
+/// reported as invalid rather than ending the run. Returns whether every code was valid.
+fn validate(input: impl BufRead, out: &mut impl Write) -> io::Result<bool> {
+    let mut valid = true;
+    for (idx, line) in input.split(b'\n').enumerate() {
+        let line = line?;
+        let code = line.trim_ascii();
+        if code.is_empty() {
+            continue;
+        }
+
+        if let Err(error) = Code::from_bytes(code) {
+            let code = String::from_utf8_lossy(code);
+            writeln!(out, "{}: {code}: {error}", idx + 1)?;
+            valid = false;
+        }
+    }
+
+    Ok(valid)
+}
+
+/// Write every valid code matching `pattern` to `out`.
+///
+/// Returns whether the pattern itself was valid.
+fn list(pattern: &str, out: &mut impl Write, err: &mut impl Write) -> io::Result<bool> {
+    let pattern = match CfiPattern::new(pattern) {
+        Ok(pattern) => pattern,
+        Err(error) => {
+            writeln!(err, "{pattern}: {error}")?;
+            return Ok(false);
+        }
+    };
+
+    for code in Code::iter_all().filter(|code| pattern.matches(code)) {
+        writeln!(out, "{code}")?;
+    }
+
+    Ok(true)
+}
+
+/// Open the file at `path` for reading, naming the path in any error.
+fn open(path: &Path) -> io::Result<BufReader<File>> {
+    File::open(path)
+        .map(BufReader::new)
+        .map_err(|error| io::Error::new(error.kind(), format!("{}: {error}", path.display())))
+}
+
+/// Run the given command against the process' standard streams.
+fn run(command: Command) -> io::Result<bool> {
+    let mut out = io::stdout().lock();
+    let mut err = io::stderr().lock();
+
+    match command {
+        Command::Decode { codes } => decode(&codes, &mut out, &mut err),
+        Command::Validate { file: Some(path) } => validate(open(&path)?, &mut out),
+        Command::Validate { file: None } => validate(io::stdin().lock(), &mut out),
+        Command::List { pattern } => list(&pattern, &mut out, &mut err),
+    }
+}
+
+/// Convert the result of running a command into the process' exit code.
+///
+/// A closed standard output, e.g. when piped into `head`, is a normal end of output.
+fn exit_code(result: io::Result<bool>) -> ExitCode {
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(error) => {
+            // Nothing more can be done if standard error is unavailable.
+            writeln!(io::stderr(), "cfi: {error}").ok();
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    exit_code(run(Cli::parse().command))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Convert captured output into a string.
+    fn output(buf: Vec<u8>) -> String {
+        String::from_utf8(buf).expect("Output was not UTF-8")
+    }
+
+    #[test]
+    fn decode_valid() {
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        let valid = decode(&["ESVUFR".to_owned()], &mut out, &mut err).expect("Write failed");
+
+        assert!(valid);
+        assert!(err.is_empty());
+
+        let out = output(out);
+        let mut lines = out.lines();
+        assert_eq!(Some("ESVUFR"), lines.next());
+        assert_eq!(Some("  category        E  Equities"), lines.next());
+        assert_eq!(
+            Some("  group           S  Common/ordinary shares"),
+            lines.next()
+        );
+        assert_eq!(4, lines.count());
+    }
+
+    #[yare::parameterized(
+        length = {"ESVUF", "ESVUF: Invalid length\n"},
+        category = {"ZSVUFR", "ZSVUFR: Invalid category 'Z'\n"},
+        attribute = {"ESZUFR", "ESZUFR: Invalid attribute character 'Z' at position '2'.\n"},
+    )]
+    fn decode_invalid(code: &str, expected: &str) {
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        let valid = decode(&[code.to_owned()], &mut out, &mut err).expect("Write failed");
+
+        assert!(!valid);
+        assert!(out.is_empty());
+        assert_eq!(expected, output(err));
+    }
+
+    #[test]
+    fn validate_lines() {
+        let input = b"ESVUFR\n\n  DBFTFB \nESZUFR\r\nXXXXXX\n";
+        let mut out = Vec::new();
+        let valid = validate(&input[..], &mut out).expect("Write failed");
+
+        assert!(!valid);
+        assert_eq!(
+            "4: ESZUFR: Invalid attribute character 'Z' at position '2'.\n\
+             5: XXXXXX: Invalid category 'X'\n",
+            output(out)
+        );
+    }
+
+    #[test]
+    fn validate_not_utf8() {
+        let input = b"ESVUFR\nESZUFR\n\xff\xfe\nesvufr\n";
+        let mut out = Vec::new();
+        let valid = validate(&input[..], &mut out).expect("Write failed");
+
+        assert!(!valid);
+        assert_eq!(
+            "2: ESZUFR: Invalid attribute character 'Z' at position '2'.\n\
+             3: \u{fffd}\u{fffd}: Invalid length\n\
+             4: esvufr: Invalid category 'e'\n",
+            output(out)
+        );
+    }
+
+    #[test]
+    fn validate_all_valid() {
+        let mut out = Vec::new();
+        let valid = validate(&b"ESVUFR\nDBFTFB\n"[..], &mut out).expect("Write failed");
+
+        assert!(valid);
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn list_pattern() {
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        let valid = list("ESVUF?", &mut out, &mut err).expect("Write failed");
+
+        assert!(valid);
+        assert!(err.is_empty());
+        assert_eq!("ESVUFB\nESVUFR\nESVUFN\nESVUFM\nESVUFX\n", output(out));
+    }
+
+    #[test]
+    fn list_invalid_pattern() {
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        let valid = list("ES**!*", &mut out, &mut err).expect("Write failed");
+
+        assert!(!valid);
+        assert!(out.is_empty());
+        assert_eq!(
+            "ES**!*: Invalid pattern character '!' at position '4'.\n",
+            output(err)
+        );
+    }
+
+    #[test]
+    fn open_missing() {
+        let path = Path::new("does/not/exist.txt");
+        let error = open(path).expect_err("Missing file was opened");
+
+        assert_eq!(io::ErrorKind::NotFound, error.kind());
+        assert!(
+            error.to_string().starts_with("does/not/exist.txt: "),
+            "{error}"
+        );
+    }
+
+    #[yare::parameterized(
+        valid = {Ok(true), ExitCode::SUCCESS},
+        invalid = {Ok(false), ExitCode::FAILURE},
+        broken_pipe = {Err(io::ErrorKind::BrokenPipe.into()), ExitCode::SUCCESS},
+        other = {Err(io::ErrorKind::NotFound.into()), ExitCode::from(2)},
+    )]
+    fn exit_code_result(result: io::Result<bool>, expected: ExitCode) {
+        assert_eq!(expected, exit_code(result));
+    }
+}
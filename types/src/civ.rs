@@ -8,7 +8,7 @@ macros::impl_category! {
     /// Securities representing a portion of assets pooled by investors run by a management company
     /// whose share capital remains separate from such assets and includes issues of shares or
     /// units in the form of, for example, a unit trust, mutual fund, OICVM, OPCVM, SICAV or SICAF.
-    pub enum Civ {
+    pub enum Civ[b'C'] {
         /// Standard (vanilla) investment funds/mutual funds.
         ///
         /// An investment vehicle that is made up of a pool of funds collected from many investors
@@ -26,7 +26,7 @@ macros::impl_category! {
         ///
         /// A REIT is a real estate company that offers shares/units to the public and invests in
         /// real estate directly, either through properties or mortgages.
-        Reit(Reit) = b'B', "R";
+        Reit(Reit) = b'B', "B";
 
         /// Exchange traded funds (ETFs).
         ///
@@ -500,3 +500,25 @@ macros::impl_attr! {
         Other = b'M', "M";
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::CFI_LENGTH;
+
+    #[yare::parameterized(
+        standard = {*b"CIXXXX", Civ::is_standard},
+        hedge = {*b"CHXXXX", Civ::is_hedge},
+        reit = {*b"CBXXXX", Civ::is_reit},
+        etf = {*b"CEXXXX", Civ::is_etf},
+        pension = {*b"CSXXXX", Civ::is_pension},
+        fund_of_funds = {*b"CFXXXX", Civ::is_fund_of_funds},
+        private_equity = {*b"CPXXXX", Civ::is_private_equity},
+        other = {*b"CMXXXX", Civ::is_other},
+    )]
+    fn from_bytes_undefined(src: [u8; CFI_LENGTH], func: fn(&Civ) -> bool) {
+        let civ = Civ::from_bytes(&src).expect("Could not parse undefined attributes");
+        assert!(func(&civ));
+        assert_eq!(src, civ.to_bytes());
+    }
+}
@@ -0,0 +1,178 @@
+//! Conformance tests against the ISO 10962:2021 code list.
+//!
+//! Every category, group, and attribute byte is checked against [`CODE_LIST`], so a mismatch
+//! between the generated parsers and the published tables fails the build rather than silently
+//! misclassifying instruments.
+
+use crate::{CFI_LENGTH, Code, Error};
+
+/// The ISO 10962:2021 code list.
+///
+/// Each entry contains the category and group characters of a group, followed by the characters
+/// permitted at each attribute position. `X` (not applicable/undefined) is permitted at every
+/// attribute position and is therefore not listed.
+const CODE_LIST: &[([u8; 2], [&[u8]; 4])] = &[
+    (*b"CI", [b"COM", b"IGJ", b"RBEVLCDFKM", b"SQUY"]),
+    (*b"CH", [b"DRSEANLM", b"", b"", b""]),
+    (*b"CB", [b"COM", b"IGJ", b"", b"SQUY"]),
+    (*b"CE", [b"COM", b"IGJ", b"RBEVLCDFKM", b"SU"]),
+    (*b"CS", [b"COM", b"BGLM", b"RBM", b"SU"]),
+    (*b"CF", [b"COM", b"IGJ", b"IHBEPM", b"SQUY"]),
+    (*b"CP", [b"COM", b"IGJ", b"RBEVLCDFKM", b"SQUY"]),
+    (*b"CM", [b"", b"", b"", b"SQUY"]),
+    (*b"DB", [b"FZVCK", b"TGSUPNOQJC", b"FGCDABTLPQRE", b"BRNM"]),
+    (*b"DC", [b"FZVK", b"TGSUPNOQJC", b"FGCDABTLPQRE", b"BRNM"]),
+    (*b"DW", [b"FZVK", b"TGSUPNOQJC", b"FGCDABTLPQRE", b"BRNM"]),
+    (*b"DT", [b"FZVK", b"TGSUPNOQJC", b"FGCDABTLPQRE", b"BRNM"]),
+    (*b"DY", [b"FZVK", b"TGSUPNOQJC", b"", b"BRNM"]),
+    (*b"DS", [b"ABCDM", b"FDVYM", b"FVM", b"BSDTCINM"]),
+    (*b"DE", [b"ABCDEM", b"FDVYM", b"RSCTM", b"BSDTCINM"]),
+    (*b"DG", [b"FZV", b"TGSUPNOQJC", b"FGCDABTLPQRE", b"BRNM"]),
+    (*b"DA", [b"FZV", b"TGSUPNOQJC", b"FGCDABTLPQRE", b"BRNM"]),
+    (*b"DN", [b"FZV", b"TGSUPNOQJC", b"FGCDABTLPQRE", b"BRNM"]),
+    (
+        *b"DD",
+        [b"BCWTYGANM", b"FZVC", b"TGSUPNOQJC", b"FGCDABTLPQRE"],
+    ),
+    (*b"DM", [b"BPM", b"", b"", b"BRNM"]),
+    (*b"ES", [b"VNRE", b"TU", b"FOP", b"BRNM"]),
+    (*b"EP", [b"VNRE", b"RETGACN", b"FCPQANUD", b"BRNM"]),
+    (*b"EC", [b"VNRE", b"TU", b"FOP", b"BRNM"]),
+    (*b"EF", [b"VNRE", b"RETGACN", b"FCPQANUD", b"BRNM"]),
+    (*b"EL", [b"VNRE", b"TU", b"FOP", b"BRNM"]),
+    (*b"ED", [b"SPCFLM", b"RNBD", b"FCPQANUD", b"BRNM"]),
+    (*b"EY", [b"ABCDEM", b"DYM", b"FVEM", b"BSDGTCINM"]),
+    (*b"EM", [b"", b"", b"", b"BRNM"]),
+    (*b"LL", [b"ABJKNPSTM", b"", b"", b"CP"]),
+    (*b"LR", [b"GSC", b"FNOT", b"", b"DHT"]),
+    (*b"LS", [b"CGPTELDWKM", b"NOT", b"", b"DFHT"]),
+    (*b"JE", [b"SIBOF", b"", b"CSF", b"CP"]),
+    (*b"JF", [b"TRFV", b"", b"CSFR", b"PCN"]),
+    (*b"JC", [b"AIBCDGOM", b"", b"CSF", b"CP"]),
+    (*b"JR", [b"IOM", b"", b"CSFA", b"CP"]),
+    (*b"JT", [b"ABGIJKNPSTM", b"", b"CSF", b"CPE"]),
+    (*b"FF", [b"BSDCIOFWNVM", b"PCN", b"SN", b""]),
+    (*b"FC", [b"EAISNPHM", b"PCN", b"SN", b""]),
+    (*b"MC", [b"SBHAWUM", b"TU", b"", b"BRNM"]),
+    (*b"MM", [b"RIETNPSM", b"", b"", b""]),
+    (*b"OC", [b"EAB", b"BSDTCIOFWNM", b"PCNE", b"SN"]),
+    (*b"OP", [b"EAB", b"BSDTCIOFWNM", b"PCNE", b"SN"]),
+    (*b"OM", [b"", b"", b"", b""]),
+    (*b"HR", [b"ACDEIHORFM", b"ABCDEFGHI", b"VADBGLPCFM", b"CPE"]),
+    (
+        *b"HT",
+        [b"JKANGPSTIHBCORWM", b"ABCDEFGHI", b"VADBGLPM", b"CPE"],
+    ),
+    (*b"HE", [b"SIBORFM", b"ABCDEFGHI", b"VADBGLPM", b"CPE"]),
+    (*b"HC", [b"UVIWM", b"ABCDEFGHI", b"VADBGLPM", b"CPE"]),
+    (*b"HF", [b"RFTVBCDEQUWYM", b"JKL", b"VADBGLPM", b"CPE"]),
+    (*b"HM", [b"PM", b"ABCDEFGHI", b"VADBGLPM", b"CPENA"]),
+    (*b"TC", [b"NLCM", b"", b"", b""]),
+    (*b"TT", [b"EAISNPHM", b"", b"", b""]),
+    (*b"TR", [b"NVFRM", b"DWNQSAM", b"", b""]),
+    (*b"TI", [b"EDFRTCM", b"PCEFM", b"PNGM", b""]),
+    (*b"TB", [b"EDFITCM", b"", b"", b""]),
+    (*b"TD", [b"SPCFLKM", b"", b"", b""]),
+    (*b"TM", [b"", b"", b"", b""]),
+    (*b"RA", [b"", b"", b"", b"BRNM"]),
+    (*b"RS", [b"SPCFBIM", b"", b"", b"BRNM"]),
+    (*b"RP", [b"SPCFBIM", b"", b"", b"BRNM"]),
+    (*b"RW", [b"BSDTCIM", b"TNC", b"CPB", b"EABM"]),
+    (*b"RF", [b"BSDTCIM", b"TNM", b"CPM", b"EABM"]),
+    (*b"RD", [b"ASPWM", b"", b"", b"BRNM"]),
+    (*b"RM", [b"", b"", b"", b""]),
+    (*b"IF", [b"", b"", b"", b"P"]),
+    (*b"IT", [b"AJKNPSTM", b"", b"", b"P"]),
+    (*b"KR", [b"", b"", b"", b""]),
+    (*b"KT", [b"", b"", b"", b""]),
+    (*b"KE", [b"", b"", b"", b""]),
+    (*b"KC", [b"", b"", b"", b""]),
+    (*b"KF", [b"", b"", b"", b""]),
+    (*b"KY", [b"", b"", b"", b""]),
+    (*b"KM", [b"", b"", b"", b""]),
+    (*b"SR", [b"ACDGHZM", b"CIDY", b"SC", b"DN"]),
+    (*b"ST", [b"JKANGPSTIHBCQM", b"CT", b"", b"CPE"]),
+    (*b"SE", [b"SIBM", b"PDVLTCM", b"", b"CPE"]),
+    (*b"SC", [b"UVIBM", b"CTM", b"CSL", b"CPA"]),
+    (*b"SF", [b"ACM", b"", b"", b"PC"]),
+    (*b"SM", [b"PM", b"", b"", b"CPE"]),
+];
+
+/// Get the list entries for the given category character.
+fn groups(category: u8) -> impl Iterator<Item = &'static ([u8; 2], [&'static [u8]; 4])> {
+    CODE_LIST
+        .iter()
+        .filter(move |(prefix, _)| prefix[0] == category)
+}
+
+/// Build a valid code for the given list entry, using the first permitted character at every
+/// attribute position.
+fn first_code(prefix: [u8; 2], attrs: &[&[u8]; 4]) -> [u8; CFI_LENGTH] {
+    let mut retval = [prefix[0], prefix[1], b'X', b'X', b'X', b'X'];
+    for (dest, allowed) in retval[2..].iter_mut().zip(attrs) {
+        if let Some(value) = allowed.first() {
+            *dest = *value;
+        }
+    }
+    retval
+}
+
+#[test]
+fn categories() {
+    for category in u8::MIN..=u8::MAX {
+        let src = [category, b'X', b'X', b'X', b'X', b'X'];
+        if groups(category).next().is_none() {
+            assert_eq!(
+                Err(Error::InvalidCategory(category as char)),
+                Code::from_bytes(&src),
+                "{src:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn groups_in_category() {
+    for (prefix, _) in CODE_LIST {
+        for group in u8::MIN..=u8::MAX {
+            let src = [prefix[0], group, b'X', b'X', b'X', b'X'];
+            if groups(prefix[0]).any(|(other, _)| other[1] == group) {
+                let code = Code::from_bytes(&src).expect("Listed group was rejected");
+                assert_eq!(src, code.to_bytes());
+            } else {
+                assert_eq!(
+                    Err(Error::InvalidGroup(group as char)),
+                    Code::from_bytes(&src),
+                    "{src:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn attributes() {
+    for (prefix, attrs) in CODE_LIST {
+        let base = first_code(*prefix, attrs);
+
+        for (pos, allowed) in attrs.iter().enumerate() {
+            let idx = pos + 2;
+
+            for value in u8::MIN..=u8::MAX {
+                let mut src = base;
+                src[idx] = value;
+
+                if value == b'X' || allowed.contains(&value) {
+                    let code = Code::from_bytes(&src).expect("Listed attribute was rejected");
+                    assert_eq!(src, code.to_bytes());
+                } else {
+                    assert_eq!(
+                        Err(Error::InvalidAttribute(idx, value as char)),
+                        Code::from_bytes(&src),
+                        "{src:?}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,374 @@
+//! Streaming validation of CFI code columns in CSV files.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use iso10962_types::{
+//!     Error,
+//!     csv::{Column, Validator},
+//! };
+//!
+//! let data = "isin,cfi\nUS0378331005,ESVUFR\nUS0000000000,ESZUFR\n";
+//! let mut validator =
+//!     Validator::new(data.as_bytes(), Column::Name("cfi")).expect("Could not read headers");
+//!
+//! let invalid = validator
+//!     .by_ref()
+//!     .collect::<Result<Vec<_>, _>>()
+//!     .expect("Could not read CSV");
+//!
+//! assert_eq!(1, invalid.len());
+//! assert_eq!(3, invalid[0].line);
+//! assert_eq!(Error::InvalidAttribute(2, 'Z'), invalid[0].error);
+//!
+//! let summary = validator.summary();
+//! assert_eq!(2, summary.rows());
+//! assert_eq!(1, summary.valid());
+//! assert_eq!(1, summary.group(b'E', b'S'));
+//! ```
+
+extern crate alloc;
+extern crate std;
+
+use crate::{CATEGORY_IDX, Code, Error, GROUP_IDX};
+use ::csv::{ByteRecord, Reader};
+use alloc::collections::BTreeMap;
+use std::io::Read;
+use thiserror::Error as ThisError;
+
+/// The column of a CSV file which contains CFI codes.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Column<'a> {
+    /// The column with the given header name.
+    Name(&'a str),
+
+    /// The column at the given zero-based index.
+    Index(usize),
+}
+
+/// An enumeration of errors which prevent a CSV file from being validated.
+#[derive(Debug, ThisError)]
+pub enum ReadError {
+    /// The CSV data could not be read or parsed.
+    #[error("CSV error: {0}")]
+    Csv(#[from] ::csv::Error),
+
+    /// The requested column does not exist in the CSV data.
+    #[error("Column not found")]
+    MissingColumn,
+}
+
+/// A row of a CSV file whose CFI column is not a valid code.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct InvalidRow {
+    /// The one-based line number the row starts on.
+    pub line: u64,
+
+    /// The reason the code is invalid.
+    pub error: Error,
+}
+
+/// Counts of the rows seen by a [`Validator`].
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Summary {
+    /// The number of rows read.
+    rows: u64,
+
+    /// The number of valid codes, keyed by category and group character.
+    groups: BTreeMap<[u8; 2], u64>,
+}
+
+impl Summary {
+    /// Record a valid code.
+    fn add(&mut self, code: Code) {
+        let bytes = code.to_bytes();
+        *self
+            .groups
+            .entry([bytes[CATEGORY_IDX], bytes[GROUP_IDX]])
+            .or_default() += 1;
+    }
+
+    /// The number of rows read.
+    #[must_use]
+    #[inline]
+    pub fn rows(&self) -> u64 {
+        self.rows
+    }
+
+    /// The number of rows which contained a valid code.
+    #[must_use]
+    #[inline]
+    pub fn valid(&self) -> u64 {
+        self.groups.values().sum()
+    }
+
+    /// The number of rows which did not contain a valid code.
+    #[must_use]
+    #[inline]
+    pub fn invalid(&self) -> u64 {
+        self.rows - self.valid()
+    }
+
+    /// The number of valid codes in the given category.
+    #[must_use]
+    #[inline]
+    pub fn category(&self, category: u8) -> u64 {
+        self.groups
+            .range([category, 0]..=[category, u8::MAX])
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    /// The number of valid codes in the given category and group.
+    #[must_use]
+    #[inline]
+    pub fn group(&self, category: u8, group: u8) -> u64 {
+        self.groups.get(&[category, group]).copied().unwrap_or(0)
+    }
+
+    /// Iterate over the number of valid codes in each category seen, in character order.
+    #[inline]
+    pub fn categories(&self) -> impl Iterator<Item = (u8, u64)> {
+        let mut counts = BTreeMap::new();
+        for ([category, _], count) in &self.groups {
+            *counts.entry(*category).or_default() += count;
+        }
+
+        counts.into_iter()
+    }
+
+    /// Iterate over the number of valid codes in each category and group seen, in character
+    /// order.
+    #[inline]
+    pub fn groups(&self) -> impl Iterator<Item = ([u8; 2], u64)> {
+        self.groups.iter().map(|(key, count)| (*key, *count))
+    }
+}
+
+/// A streaming validator for a CFI code column of a CSV file.
+///
+/// Iterating yields each row whose code is invalid, while the counts for every row are kept in
+/// the [`Summary`]. Only one record is held in memory at a time, so input of any size may be
+/// validated.
+#[derive(Debug)]
+pub struct Validator<R> {
+    /// The CSV reader.
+    reader: Reader<R>,
+
+    /// The index of the CFI column.
+    column: usize,
+
+    /// The buffer records are read into.
+    record: ByteRecord,
+
+    /// The counts of the rows read so far.
+    summary: Summary,
+}
+
+impl<R: Read> Validator<R> {
+    /// Create a new validator for CSV data with a header row.
+    ///
+    /// # Errors
+    ///
+    /// - [`ReadError::Csv`] if the header row could not be read.
+    /// - [`ReadError::MissingColumn`] if the column is not in the header row.
+    #[inline]
+    pub fn new(reader: R, column: Column<'_>) -> Result<Self, ReadError> {
+        Self::from_reader(Reader::from_reader(reader), column)
+    }
+
+    /// Create a new validator using a pre-configured CSV reader.
+    ///
+    /// This allows the delimiter, quoting, or the presence of a header row to be changed. Named
+    /// columns require the reader to have a header row.
+    ///
+    /// # Errors
+    ///
+    /// - [`ReadError::Csv`] if the header row could not be read.
+    /// - [`ReadError::MissingColumn`] if the column is not in the header row.
+    #[inline]
+    pub fn from_reader(mut reader: Reader<R>, column: Column<'_>) -> Result<Self, ReadError> {
+        let column = match column {
+            Column::Name(name) if reader.has_headers() => reader
+                .byte_headers()?
+                .iter()
+                .position(|header| header == name.as_bytes())
+                .ok_or(ReadError::MissingColumn)?,
+            Column::Name(_) => return Err(ReadError::MissingColumn),
+            Column::Index(index) => index,
+        };
+
+        Ok(Self {
+            reader,
+            column,
+            record: ByteRecord::new(),
+            summary: Summary::default(),
+        })
+    }
+
+    /// The counts of the rows read so far.
+    #[must_use]
+    #[inline]
+    pub fn summary(&self) -> &Summary {
+        &self.summary
+    }
+
+    /// Consume the validator, returning the counts of the rows read.
+    #[must_use]
+    #[inline]
+    pub fn into_summary(self) -> Summary {
+        self.summary
+    }
+}
+
+impl<R: Read> Iterator for Validator<R> {
+    type Item = Result<InvalidRow, ReadError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.reader.read_byte_record(&mut self.record) {
+                Ok(true) => {}
+                Ok(false) => return None,
+                Err(error) => return Some(Err(error.into())),
+            }
+
+            self.summary.rows += 1;
+            let Some(value) = self.record.get(self.column) else {
+                return Some(Err(ReadError::MissingColumn));
+            };
+
+            match Code::from_bytes(value) {
+                Ok(code) => self.summary.add(code),
+                Err(error) => {
+                    let line = self.record.position().map_or(0, ::csv::Position::line);
+                    return Some(Ok(InvalidRow { line, error }));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::csv::ReaderBuilder;
+    use alloc::vec::Vec;
+
+    /// Validate the given data, returning the invalid rows and the summary.
+    fn run<R: Read>(mut validator: Validator<R>) -> (Vec<InvalidRow>, Summary) {
+        let invalid = validator
+            .by_ref()
+            .collect::<Result<Vec<_>, _>>()
+            .expect("Could not read CSV");
+        (invalid, validator.into_summary())
+    }
+
+    #[test]
+    fn named_column() {
+        let data = "isin,cfi,name\n\
+                    US0378331005,ESVUFR,Apple\n\
+                    US5949181045,ESVUFR,Microsoft\n\
+                    XS0000000001,DBFTFB,Bond\n\
+                    XS0000000002,ZZZZZZ,Bad category\n\
+                    XS0000000003,ESVUF,Short\n\
+                    XS0000000004,OCASPS,Option\n";
+        let validator =
+            Validator::new(data.as_bytes(), Column::Name("cfi")).expect("Could not read headers");
+        let (invalid, summary) = run(validator);
+
+        assert_eq!(
+            [
+                InvalidRow {
+                    line: 5,
+                    error: Error::InvalidCategory('Z'),
+                },
+                InvalidRow {
+                    line: 6,
+                    error: Error::InvalidLength,
+                },
+            ],
+            invalid.as_slice()
+        );
+
+        assert_eq!(6, summary.rows());
+        assert_eq!(4, summary.valid());
+        assert_eq!(2, summary.invalid());
+        assert_eq!(2, summary.category(b'E'));
+        assert_eq!(1, summary.category(b'D'));
+        assert_eq!(0, summary.category(b'C'));
+        assert_eq!(2, summary.group(b'E', b'S'));
+        assert_eq!(0, summary.group(b'E', b'P'));
+        assert_eq!(
+            [(b'D', 1), (b'E', 2), (b'O', 1)],
+            summary.categories().collect::<Vec<_>>().as_slice()
+        );
+        assert_eq!(
+            [(*b"DB", 1), (*b"ES", 2), (*b"OC", 1)],
+            summary.groups().collect::<Vec<_>>().as_slice()
+        );
+    }
+
+    #[test]
+    fn indexed_column_without_headers() {
+        let data = "ESVUFR;1\nESXXXX;2\nESZUFR;3\n";
+        let reader = ReaderBuilder::new()
+            .has_headers(false)
+            .delimiter(b';')
+            .from_reader(data.as_bytes());
+        let validator = Validator::from_reader(reader, Column::Index(0)).expect("Invalid column");
+        let (invalid, summary) = run(validator);
+
+        assert_eq!(
+            [InvalidRow {
+                line: 3,
+                error: Error::InvalidAttribute(2, 'Z'),
+            }],
+            invalid.as_slice()
+        );
+        assert_eq!(3, summary.rows());
+        assert_eq!(2, summary.group(b'E', b'S'));
+    }
+
+    #[test]
+    fn quoted_multiline_line_numbers() {
+        let data = "name,cfi\n\"multi\nline\",ESVUFR\nbad,XXXXXX\n";
+        let validator =
+            Validator::new(data.as_bytes(), Column::Index(1)).expect("Could not read headers");
+        let (invalid, _) = run(validator);
+
+        assert_eq!(
+            [InvalidRow {
+                line: 4,
+                error: Error::InvalidCategory('X'),
+            }],
+            invalid.as_slice()
+        );
+    }
+
+    #[test]
+    fn missing_named_column() {
+        let result = Validator::new(&b"isin,name\n"[..], Column::Name("cfi"));
+        assert!(matches!(result, Err(ReadError::MissingColumn)));
+    }
+
+    #[test]
+    fn missing_indexed_column() {
+        let mut validator = Validator::new(&b"cfi\nESVUFR\n"[..], Column::Index(1))
+            .expect("Could not read headers");
+        assert!(matches!(
+            validator.next(),
+            Some(Err(ReadError::MissingColumn))
+        ));
+    }
+
+    #[test]
+    fn unequal_rows() {
+        let mut validator = Validator::new(
+            &b"isin,cfi\nUS0378331005,ESVUFR,extra\n"[..],
+            Column::Index(1),
+        )
+        .expect("Could not read headers");
+        assert!(matches!(validator.next(), Some(Err(ReadError::Csv(_)))));
+    }
+}
@@ -3,9 +3,11 @@
 use crate::{Form, NotApplicable, macros};
 
 macros::impl_category! {
+    /// Debt instruments.
+    ///
     /// Financial instruments evidencing monies owed by the issuer to the holder on terms as
     /// specified.
-    pub enum Debt {
+    pub enum Debt[b'D'] {
         /// Bonds.
         ///
         /// Any interest-bearing or discounted security that normally obliges the issuer to pay the
@@ -454,6 +456,9 @@ macros::impl_attr! {
     /// be used for unsecured securities that are neither senior nor junior. `U`
     /// ([`Unsecured`](Guarantee::Unsecured)) may be used only if one of these codes does not apply
     /// to the relevant security.
+    ///
+    /// Whether a security is secured, senior or junior is not otherwise recorded in the code, so
+    /// these guidelines cannot be checked by [`Validate`](crate::validate::Validate).
     pub enum Guarantee[3, 4] {
         /// Government guarantee.
         ///
@@ -645,13 +650,13 @@ macros::impl_attr! {
     /// Indicates the cash distribution provided by the structured instrument.
     pub enum Distribution[3] {
         /// Fixed interest payments.
-        Fixed = b'F', 1;
+        Fixed = b'F', "F";
 
         /// Dividend payments.
-        Dividend = b'D', 2;
+        Dividend = b'D', "D";
 
         /// Variable interest payments.
-        Variable = b'V', 3;
+        Variable = b'V', "V";
 
         /// No payments.
         None = b'Y', "Y";
@@ -879,3 +884,29 @@ macros::impl_attr! {
         Other = b'M', "M";
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::CFI_LENGTH;
+
+    #[yare::parameterized(
+        bond = {*b"DBXXXX", Debt::is_bond},
+        convertible = {*b"DCXXXX", Debt::is_convertible},
+        warrant_attached = {*b"DWXXXX", Debt::is_warrant_attached},
+        medium_term = {*b"DTXXXX", Debt::is_medium_term},
+        money_market = {*b"DYXXXX", Debt::is_money_market},
+        protected_structured = {*b"DSXXXX", Debt::is_protected_structured},
+        unprotected_structured = {*b"DEXXXX", Debt::is_unprotected_structured},
+        mortgage_backed = {*b"DGXXXX", Debt::is_mortgage_backed},
+        asset_backed = {*b"DAXXXX", Debt::is_asset_backed},
+        municipal = {*b"DNXXXX", Debt::is_municipal},
+        depository = {*b"DDXXXX", Debt::is_depository},
+        other = {*b"DMXXXX", Debt::is_other},
+    )]
+    fn from_bytes_undefined(src: [u8; CFI_LENGTH], func: fn(&Debt) -> bool) {
+        let debt = Debt::from_bytes(&src).expect("Could not parse undefined attributes");
+        assert!(func(&debt));
+        assert_eq!(src, debt.to_bytes());
+    }
+}
@@ -0,0 +1,99 @@
+//! Human-readable descriptions of codes.
+
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The name and short description of a single character of a CFI code.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Label {
+    /// The field this character describes, e.g. `category`, `group`, or `voting_right`.
+    pub field: &'static str,
+
+    /// The name of the variant, e.g. `Voting`.
+    pub name: &'static str,
+
+    /// The short description of the variant, e.g. `Voting`.
+    pub description: &'static str,
+}
+
+/// A human-readable description of a CFI code.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso10962_types::cfi;
+///
+/// let description = cfi!("ESVUFR").describe();
+///
+/// assert_eq!("Common", description.group.name);
+/// assert_eq!(
+///     "Equities / Common/ordinary shares / Voting / Free / Fully paid / Registered",
+///     format!("{description}"),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Description {
+    /// The category of the code.
+    pub category: Label,
+
+    /// The group of the code.
+    pub group: Label,
+
+    /// The four attributes of the code.
+    pub attributes: [Label; 4],
+}
+
+impl Description {
+    /// Iterate over the labels of every character of the code, in order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &Label> {
+        [&self.category, &self.group]
+            .into_iter()
+            .chain(&self.attributes)
+    }
+}
+
+impl Display for Description {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for (idx, label) in self.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(" / ")?;
+            }
+            f.write_str(label.description)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Extract the short description from the given doc comment text.
+///
+/// This is the first line of the documentation, up to any parenthetical remark or period.
+pub(crate) const fn short_description(doc: &'static str) -> &'static str {
+    let bytes = doc.as_bytes();
+    let mut end = 0;
+    while end < bytes.len() && !matches!(bytes[end], b'\n' | b'(' | b'.') {
+        end += 1;
+    }
+
+    doc.split_at(end).0.trim_ascii()
+}
+
+/// Extract the title of a category or group from the given doc comment text.
+///
+/// This is the first line of the documentation, without any trailing period. Unlike
+/// [`short_description()`], parenthetical remarks are kept, as they distinguish groups such as
+/// structured products with and without capital protection.
+pub(crate) const fn title(doc: &'static str) -> &'static str {
+    let bytes = doc.as_bytes();
+    let mut end = 0;
+    while end < bytes.len() && bytes[end] != b'\n' {
+        end += 1;
+    }
+
+    let line = doc.split_at(end).0.trim_ascii();
+    match line.as_bytes() {
+        [.., b'.'] => line.split_at(line.len() - 1).0,
+        _ => line,
+    }
+}
@@ -0,0 +1,257 @@
+//! Diagnostics covering every invalid character of a code.
+
+use crate::{CATEGORY_IDX, CFI_LENGTH, Error, GROUP_IDX, list::FixedList};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    iter::Flatten,
+    slice::Iter,
+};
+
+/// An invalid character of a code.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Diagnostic {
+    /// The position of the character within the code.
+    pub idx: usize,
+
+    /// The invalid character.
+    pub value: char,
+
+    /// The field of the character, e.g. `category`, `group`, or `voting_right`.
+    pub field: &'static str,
+
+    /// The characters which are valid at this position.
+    pub allowed: &'static [u8],
+}
+
+impl Diagnostic {
+    /// The error which [`Code::from_bytes()`](crate::Code::from_bytes) reports for this character.
+    #[must_use]
+    #[inline]
+    pub const fn error(&self) -> Error {
+        match self.idx {
+            CATEGORY_IDX => Error::InvalidCategory(self.value),
+            GROUP_IDX => Error::InvalidGroup(self.value),
+            idx => Error::InvalidAttribute(idx, self.value),
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "Invalid {} character '{}' at position '{}', expected one of: ",
+            self.field, self.value, self.idx
+        )?;
+        fmt_allowed(self.allowed, f)
+    }
+}
+
+/// Write the given allowed characters as a comma-separated list.
+pub(crate) fn fmt_allowed(allowed: &[u8], f: &mut Formatter<'_>) -> FmtResult {
+    for (idx, value) in allowed.iter().enumerate() {
+        if idx > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{}", char::from(*value))?;
+    }
+
+    Ok(())
+}
+
+/// A fixed-capacity list of every problem found in a code.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso10962_types::{Code, Error};
+///
+/// let diagnostics = Code::diagnose(b"ESZUZQ");
+///
+/// assert!(!diagnostics.is_valid());
+/// assert!(!diagnostics.invalid_length());
+/// assert_eq!(
+///     [
+///         Error::InvalidAttribute(2, 'Z'),
+///         Error::InvalidAttribute(4, 'Z'),
+///         Error::InvalidAttribute(5, 'Q'),
+///     ],
+///     *diagnostics
+///         .iter()
+///         .map(|diagnostic| diagnostic.error())
+///         .collect::<Vec<_>>(),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Diagnostics {
+    /// The diagnostics found.
+    list: FixedList<Diagnostic, CFI_LENGTH>,
+
+    /// Whether the input was not [`CFI_LENGTH`] bytes.
+    invalid_length: bool,
+}
+
+impl Diagnostics {
+    /// Create a new, empty list of diagnostics.
+    pub(crate) const fn new(invalid_length: bool) -> Self {
+        Self {
+            list: FixedList::new(),
+            invalid_length,
+        }
+    }
+
+    /// Append a diagnostic to the list.
+    pub(crate) const fn push(&mut self, diagnostic: Diagnostic) {
+        self.list.push(diagnostic);
+    }
+
+    /// Whether the input was not [`CFI_LENGTH`] bytes.
+    ///
+    /// The characters which were present are still checked.
+    #[must_use]
+    #[inline]
+    pub const fn invalid_length(&self) -> bool {
+        self.invalid_length
+    }
+
+    /// Whether the input was a valid code.
+    #[must_use]
+    #[inline]
+    pub const fn is_valid(&self) -> bool {
+        !self.invalid_length && self.is_empty()
+    }
+
+    /// Whether no invalid characters were found.
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// The number of invalid characters found.
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// The first invalid character found.
+    #[must_use]
+    #[inline]
+    pub const fn first(&self) -> Option<Diagnostic> {
+        self.list.first()
+    }
+
+    /// Iterate over the invalid characters found, in code order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Diagnostics {
+    type Item = &'a Diagnostic;
+    type IntoIter = Flatten<Iter<'a, Option<Diagnostic>>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::Code;
+    use alloc::{string::ToString as _, vec::Vec};
+
+    /// Collect the errors of the given diagnostics.
+    fn errors(diagnostics: &Diagnostics) -> Vec<Error> {
+        diagnostics.iter().map(Diagnostic::error).collect()
+    }
+
+    #[yare::parameterized(
+        category = {b"ZSVUFR", &[Error::InvalidCategory('Z')]},
+        group = {b"EZVUFR", &[Error::InvalidGroup('Z')]},
+        category_hides_attributes = {b"ZZZZZZ", &[Error::InvalidCategory('Z')]},
+        group_hides_attributes = {b"EZZZZZ", &[Error::InvalidGroup('Z')]},
+        every_attribute = {
+            b"ESZZZZ",
+            &[
+                Error::InvalidAttribute(2, 'Z'),
+                Error::InvalidAttribute(3, 'Z'),
+                Error::InvalidAttribute(4, 'Z'),
+                Error::InvalidAttribute(5, 'Z'),
+            ]
+        },
+        not_applicable = {b"IFAAAA", &[Error::InvalidAttribute(2, 'A'), Error::InvalidAttribute(3, 'A'), Error::InvalidAttribute(4, 'A'), Error::InvalidAttribute(5, 'A')]},
+        debt = {b"DBQQQQ", &[Error::InvalidAttribute(2, 'Q'), Error::InvalidAttribute(5, 'Q')]},
+    )]
+    fn diagnose(src: &[u8], expected: &[Error]) {
+        let diagnostics = Code::diagnose(src);
+        assert!(!diagnostics.is_valid());
+        assert!(!diagnostics.invalid_length());
+        assert_eq!(expected, errors(&diagnostics).as_slice());
+
+        let first = Code::from_bytes(src).expect_err("Code was valid");
+        assert_eq!(Some(&first), expected.first());
+    }
+
+    #[yare::parameterized(
+        empty = {b"", &[]},
+        short_valid = {b"ESVUF", &[]},
+        short_invalid = {b"ESZ", &[Error::InvalidAttribute(2, 'Z')]},
+        long_valid = {b"ESVUFRX", &[]},
+        long_invalid = {b"ESZUFRZ", &[Error::InvalidAttribute(2, 'Z')]},
+    )]
+    fn diagnose_length(src: &[u8], expected: &[Error]) {
+        let diagnostics = Code::diagnose(src);
+        assert!(!diagnostics.is_valid());
+        assert!(diagnostics.invalid_length());
+        assert_eq!(expected, errors(&diagnostics).as_slice());
+    }
+
+    #[test]
+    fn diagnose_valid() {
+        for code in Code::iter_all().step_by(89) {
+            let diagnostics = Code::diagnose(&code.to_bytes());
+            assert!(diagnostics.is_valid());
+            assert!(diagnostics.is_empty());
+        }
+    }
+
+    #[test]
+    fn allowed() {
+        let diagnostics = Code::diagnose(b"EZVUFZ");
+        let group = diagnostics.iter().next().expect("Missing group diagnostic");
+        assert_eq!("group", group.field);
+        assert_eq!(b"SPCFLDYM", group.allowed);
+
+        let diagnostics = Code::diagnose(b"ESVUFZ");
+        let form = diagnostics.iter().next().expect("Missing form diagnostic");
+        assert_eq!(5, form.idx);
+        assert_eq!("form", form.field);
+        assert_eq!(b"BRNMX", form.allowed);
+        assert_eq!(
+            "Invalid form character 'Z' at position '5', expected one of: B, R, N, M, X",
+            form.to_string()
+        );
+    }
+
+    #[test]
+    fn categories() {
+        let diagnostics = Code::diagnose(b"ZSVUFR");
+        let category = diagnostics
+            .iter()
+            .next()
+            .expect("Missing category diagnostic");
+        assert_eq!("category", category.field);
+        assert_eq!(Code::CATEGORIES, category.allowed);
+        for category in Code::CATEGORIES {
+            assert!(Code::iter_all().any(|code| code.to_bytes()[CATEGORY_IDX] == *category));
+        }
+    }
+}
@@ -1,10 +1,15 @@
 //! Equities category support.
 
-use crate::{Form, NotApplicable, macros};
+use crate::{
+    Form, GroupInfo as _, NotApplicable, macros,
+    validate::{Violation, Violations},
+};
 
 macros::impl_category! {
+    /// Equities.
+    ///
     /// Financial instruments representing an ownership interest in an entity or pool of assets.
-    pub enum Equity {
+    pub enum Equity[b'E'] {
         /// Common/ordinary shares.
         ///
         /// Holders are typically entitled to vote and receive dividends. In the event of
@@ -44,7 +49,7 @@ macros::impl_category! {
         /// they have no management authority. The GPs pay the LPs the equivalent of a dividend on
         /// their investment, the nature and extent of which is usually defined in the partnership
         /// agreement.
-        LlpUnit(LlpUnit) = b'L', "F";
+        LlpUnit(LlpUnit) = b'L', "L";
 
         /// Depository receipts on equities.
         ///
@@ -62,7 +67,7 @@ macros::impl_category! {
         /// together provide the desired disbursement profile.
         Structured(Structured) = b'Y', "Y";
 
-        /// Others (miscelaneous).
+        /// Others (miscellaneous).
         Other(Other) = b'M', "M";
     }
 }
@@ -186,7 +191,7 @@ macros::impl_group! {
     /// Depository receipts are securities that facilitate the ownership of securities traded in other
     /// jurisdictions. Depository receipts are widely used in order to allow the trading of shares in
     /// jurisdictions other than the one where the original shares were issued.
-    pub struct DepositoryReceipt {
+    pub struct DepositoryReceipt[violations] {
         /// Instrument dependency (represents the ownership of an instrument provided in this
         /// table).
         pub dependency: Dependency, 1;
@@ -383,6 +388,11 @@ macros::impl_attr! {
         ///
         /// Dividend is adjusted through an auction, such as the Dutch auction.
         AuctionRate = b'U', "U";
+
+        /// Dividends.
+        ///
+        /// The holder is entitled to dividends, without any further specification.
+        Dividend = b'D', "D";
     }
 }
 
@@ -419,6 +429,8 @@ macros::impl_attr! {
     /// For common/ordinary shares and limited partnership units, only the values `N`
     /// ([`Perpetual`](RedemptionConversion::Perpetual)) `X` (not
     /// applicable/undefined) may be used. All values apply for other underlying instruments.
+    ///
+    /// These guidelines are checked by [`Validate`](crate::validate::Validate).
     pub enum RedemptionConversion[3] {
         /// Redeemable.
         Redeemable = b'R', "R";
@@ -557,3 +569,47 @@ macros::impl_attr! {
         Other = b'M', "M";
     }
 }
+
+impl DepositoryReceipt {
+    /// Check the redemption/conversion guideline of depository receipts on common/ordinary shares
+    /// and limited partnership units.
+    const fn violations(self) -> Violations {
+        let mut violations = Violations::new();
+        let redemption = self.redemption();
+        if matches!(self.dependency(), Dependency::Common | Dependency::LlpUnit)
+            && !matches!(
+                redemption,
+                RedemptionConversion::Perpetual | RedemptionConversion::Undefined
+            )
+        {
+            violations.push(Violation::PerpetualOnly(
+                Self::POSITIONS[1],
+                redemption.to_byte() as char,
+            ));
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::CFI_LENGTH;
+
+    #[yare::parameterized(
+        common = {*b"ESXXXX", Equity::is_common},
+        preferred = {*b"EPXXXX", Equity::is_preferred},
+        convertible = {*b"ECXXXX", Equity::is_convertible},
+        prefered_convertible = {*b"EFXXXX", Equity::is_prefered_convertible},
+        llp_unit = {*b"ELXXXX", Equity::is_llp_unit},
+        depository_receipt = {*b"EDXXXX", Equity::is_depository_receipt},
+        structured = {*b"EYXXXX", Equity::is_structured},
+        other = {*b"EMXXXX", Equity::is_other},
+    )]
+    fn from_bytes_undefined(src: [u8; CFI_LENGTH], func: fn(&Equity) -> bool) {
+        let equity = Equity::from_bytes(&src).expect("Could not parse undefined attributes");
+        assert!(func(&equity));
+        assert_eq!(src, equity.to_bytes());
+    }
+}
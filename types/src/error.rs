@@ -1,6 +1,11 @@
 //! Error details.
 
-use core::result::Result as CoreResult;
+use crate::{CATEGORY_IDX, Code, GROUP_IDX, diagnose};
+use core::{
+    error::Error as CoreError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    result::Result as CoreResult,
+};
 use thiserror::Error as ThisError;
 
 /// An enumeration of errors which this library may generate.
@@ -23,5 +28,183 @@ pub enum Error {
     InvalidAttribute(usize, char),
 }
 
+impl Error {
+    /// Whether this error is equal to the given error, usable in const contexts.
+    const fn same_as(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::InvalidLength, Self::InvalidLength) => true,
+            (Self::InvalidCategory(lhs), Self::InvalidCategory(rhs))
+            | (Self::InvalidGroup(lhs), Self::InvalidGroup(rhs)) => *lhs == *rhs,
+            (Self::InvalidAttribute(lhs_idx, lhs), Self::InvalidAttribute(rhs_idx, rhs)) => {
+                *lhs_idx == *rhs_idx && *lhs == *rhs
+            }
+            _ => false,
+        }
+    }
+}
+
+/// An [`Error`] with the context needed to explain how to fix the code.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso10962_types::{Code, Error};
+///
+/// let error = Code::from_bytes_detailed(b"ESZUFR").expect_err("Code was valid");
+///
+/// assert_eq!(Error::InvalidAttribute(2, 'Z'), error.error);
+/// assert_eq!(Some('E'), error.category);
+/// assert_eq!(Some('S'), error.group);
+/// assert_eq!(Some("voting_right"), error.field);
+/// assert_eq!(b"VNREX", error.allowed);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DetailedError {
+    /// The underlying error.
+    pub error: Error,
+
+    /// The category in effect, if the category was valid.
+    pub category: Option<char>,
+
+    /// The group in effect, if the group was valid.
+    pub group: Option<char>,
+
+    /// The field of the invalid character, e.g. `category`, `group`, or `voting_right`.
+    pub field: Option<&'static str>,
+
+    /// The characters which would have been valid in place of the invalid character.
+    pub allowed: &'static [u8],
+}
+
+impl DetailedError {
+    /// Add the context of the given source bytes to an error parsing them.
+    pub(crate) const fn new(error: Error, src: &[u8]) -> Self {
+        let mut retval = Self {
+            error,
+            category: None,
+            group: None,
+            field: None,
+            allowed: &[],
+        };
+
+        // The characters present may also be invalid when the length is wrong, so only describe
+        // the character the error refers to.
+        if let Some(diagnostic) = Code::diagnose(src).first() {
+            if diagnostic.error().same_as(&error) {
+                if diagnostic.idx > CATEGORY_IDX {
+                    retval.category = Some(src[CATEGORY_IDX] as char);
+                }
+                if diagnostic.idx > GROUP_IDX {
+                    retval.group = Some(src[GROUP_IDX] as char);
+                }
+                retval.field = Some(diagnostic.field);
+                retval.allowed = diagnostic.allowed;
+            }
+        }
+
+        retval
+    }
+}
+
+impl Display for DetailedError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.error)?;
+
+        let Some(field) = self.field else {
+            return Ok(());
+        };
+
+        // Only the attribute message ends its own sentence.
+        if !matches!(self.error, Error::InvalidAttribute(..)) {
+            f.write_str(".")?;
+        }
+
+        write!(f, " Field '{field}'")?;
+        if let Some(category) = self.category {
+            write!(f, " of category '{category}'")?;
+        }
+        if let Some(group) = self.group {
+            write!(f, ", group '{group}'")?;
+        }
+        f.write_str(", expected one of: ")?;
+        diagnose::fmt_allowed(self.allowed, f)?;
+        f.write_str(".")
+    }
+}
+
+impl CoreError for DetailedError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn CoreError + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<DetailedError> for Error {
+    #[inline]
+    fn from(value: DetailedError) -> Self {
+        value.error
+    }
+}
+
 /// A convenience wrapper for results generated by this library.
 pub type Result<T, E = Error> = CoreResult<T, E>;
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::string::ToString as _;
+
+    #[yare::parameterized(
+        length = {b"ESVUF", Error::InvalidLength, None, None, None, b""},
+        short_invalid = {b"ESZ", Error::InvalidLength, None, None, None, b""},
+        long_invalid = {b"ESZUFRZ", Error::InvalidLength, None, None, None, b""},
+        short_invalid_category = {b"ZSVUF", Error::InvalidLength, None, None, None, b""},
+        category = {b"ZSVUFR", Error::InvalidCategory('Z'), None, None, Some("category"), Code::CATEGORIES},
+        group = {b"EZVUFR", Error::InvalidGroup('Z'), Some('E'), None, Some("group"), b"SPCFLDYM"},
+        attribute = {b"ESVUFZ", Error::InvalidAttribute(5, 'Z'), Some('E'), Some('S'), Some("form"), b"BRNMX"},
+        first_attribute = {b"DBQQQQ", Error::InvalidAttribute(2, 'Q'), Some('D'), Some('B'), Some("kind"), b"FZVCKX"},
+    )]
+    fn from_bytes_detailed(
+        src: &[u8],
+        error: Error,
+        category: Option<char>,
+        group: Option<char>,
+        field: Option<&str>,
+        allowed: &[u8],
+    ) {
+        let detailed = Code::from_bytes_detailed(src).expect_err("Code was valid");
+        assert_eq!(error, detailed.error);
+        assert_eq!(category, detailed.category);
+        assert_eq!(group, detailed.group);
+        assert_eq!(field, detailed.field);
+        assert_eq!(allowed, detailed.allowed);
+        assert_eq!(Err(error), Code::from_bytes(src));
+        assert_eq!(error, Error::from(detailed));
+    }
+
+    #[yare::parameterized(
+        length = {b"ESVUF", "Invalid length"},
+        short_invalid = {b"ESZ", "Invalid length"},
+        long_invalid = {b"ESZUFRZ", "Invalid length"},
+        category = {b"ZSVUFR", "Invalid category 'Z'. Field 'category', expected one of: E, D, C, R, O, F, S, H, I, J, K, L, T, M."},
+        group = {b"IZXXXX", "Invalid group 'Z'. Field 'group' of category 'I', expected one of: F, T."},
+        attribute = {b"ESVUFZ", "Invalid attribute character 'Z' at position '5'. Field 'form' of category 'E', group 'S', expected one of: B, R, N, M, X."},
+    )]
+    fn detailed_display(src: &[u8], expected: &str) {
+        let detailed = Code::from_bytes_detailed(src).expect_err("Code was valid");
+        assert_eq!(expected, detailed.to_string());
+    }
+
+    #[test]
+    fn detailed_source() {
+        let detailed = Code::from_bytes_detailed(b"ESVUFZ").expect_err("Code was valid");
+        let source = detailed.source().expect("No source error");
+        assert_eq!(
+            Some(&Error::InvalidAttribute(5, 'Z')),
+            source.downcast_ref::<Error>()
+        );
+    }
+}
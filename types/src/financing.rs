@@ -0,0 +1,348 @@
+//! Financing category support.
+
+use crate::{NotApplicable, macros};
+
+macros::impl_category! {
+    /// Financing.
+    ///
+    /// Financing is a collateralized loan agreement entered into between two parties where one
+    /// party, the lender, lends (temporarily) the underlying asset which is secured with cash or
+    /// other acceptable collateral (securities or other assets) provided by the borrower.
+    /// Depending on the exact type of financing transaction, a simultaneous agreement to reverse
+    /// the agreement may be entered into at the same time with an agreed-upon future date for the
+    /// reverse transaction to take place.
+    pub enum Financing[b'L'] {
+        /// Loan-lease.
+        ///
+        /// A loan or lease of a specified commodity from one party to another, where the borrower
+        /// agrees to return the commodity, or an equivalent, at a future date.
+        LoanLease(LoanLease) = b'L', "L";
+
+        /// Repurchase agreements.
+        ///
+        /// A repurchase agreement is an agreement between two parties where one party sells the
+        /// other a security at a specified price with a commitment to buy the security back at a
+        /// later date for another specified price. This includes sell/buy-back and buy/sell-back
+        /// transactions.
+        Repurchase(Repurchase) = b'R', "R";
+
+        /// Securities lending.
+        ///
+        /// A transaction by which one party transfers securities to another party subject to a
+        /// commitment that the borrower will return equivalent securities on a future date or when
+        /// requested to do so by the lender.
+        SecuritiesLending(SecuritiesLending) = b'S', "S";
+    }
+}
+
+macros::impl_group! {
+    /// Loan-lease.
+    ///
+    /// A loan or lease of a specified commodity from one party to another, where the borrower
+    /// agrees to return the commodity, or an equivalent, at a future date.
+    pub struct LoanLease {
+        /// Underlying assets.
+        pub underlying: LoanLeaseUnderlying, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Delivery.
+        pub delivery: LoanLeaseDelivery, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Repurchase agreements.
+    ///
+    /// A repurchase agreement is an agreement between two parties where one party sells the other
+    /// a security at a specified price with a commitment to buy the security back at a later date
+    /// for another specified price. This includes sell/buy-back and buy/sell-back transactions.
+    pub struct Repurchase {
+        /// Underlying assets.
+        pub underlying: RepurchaseUnderlying, 1;
+
+        /// Termination.
+        ///
+        /// Indicates how, and when, the agreement terminates.
+        pub termination: RepurchaseTermination, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Delivery.
+        ///
+        /// Indicates how the collateral is delivered.
+        pub delivery: RepurchaseDelivery, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Securities lending.
+    ///
+    /// A transaction by which one party transfers securities to another party subject to a
+    /// commitment that the borrower will return equivalent securities on a future date or when
+    /// requested to do so by the lender.
+    pub struct SecuritiesLending {
+        /// Underlying assets.
+        pub underlying: LendingUnderlying, 1;
+
+        /// Termination.
+        ///
+        /// Indicates how, and when, the agreement terminates.
+        pub termination: LendingTermination, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Delivery.
+        ///
+        /// Indicates how the collateral is delivered.
+        pub delivery: LendingDelivery, 4;
+    }
+}
+
+macros::impl_attr! {
+    /// Underlying assets for loan-lease agreements.
+    pub enum LoanLeaseUnderlying[2] {
+        /// Agriculture.
+        ///
+        /// Commodities which include forestry, fishing, livestock, grain, dairy, corn, cocoa,
+        /// soybeans, sugar, coffee.
+        Agriculture = b'A', "A";
+
+        /// Baskets.
+        Baskets = b'B', "B";
+
+        /// Energy.
+        ///
+        /// An energy-related product, including electricity, renewable energy, or any
+        /// power/energy delivered through a utility network or provider.
+        Energy = b'J', "J";
+
+        /// Metals.
+        ///
+        /// A precious or industrial metal, such as aluminium, copper, gold, lead, nickel,
+        /// platinum, silver, tin, zinc.
+        Metals = b'K', "K";
+
+        /// Environmental.
+        ///
+        /// Includes carbon-related, emission reduction, weather.
+        Environmental = b'N', "N";
+
+        /// Polypropylene products.
+        Polypropylene = b'P', "P";
+
+        /// Fertilizer.
+        Fertilizer = b'S', "S";
+
+        /// Paper.
+        Paper = b'T', "T";
+
+        /// Others (miscellaneous).
+        Other = b'M', "M";
+    }
+}
+
+macros::impl_attr! {
+    /// Loan-lease delivery.
+    pub enum LoanLeaseDelivery[5] {
+        /// Cash.
+        Cash = b'C', "C";
+
+        /// Physical.
+        Physical = b'P', "P";
+    }
+}
+
+macros::impl_attr! {
+    /// Underlying assets for repurchase agreements.
+    pub enum RepurchaseUnderlying[2] {
+        /// General collateral.
+        ///
+        /// The collateral is any security from a pre-agreed set of securities, rather than a
+        /// specific security.
+        General = b'G', "G";
+
+        /// Specific security collateral.
+        ///
+        /// The collateral is a specific, named security.
+        Specific = b'S', "S";
+
+        /// Cash collateral.
+        Cash = b'C', "C";
+    }
+}
+
+macros::impl_attr! {
+    /// Repurchase agreement termination.
+    pub enum RepurchaseTermination[3] {
+        /// Flexible.
+        ///
+        /// The agreement has a flexible termination date.
+        Flexible = b'F', "F";
+
+        /// Overnight.
+        ///
+        /// The agreement terminates on the next business day.
+        Overnight = b'N', "N";
+
+        /// Open.
+        ///
+        /// The agreement has no fixed termination date and continues until either party
+        /// terminates it.
+        Open = b'O', "O";
+
+        /// Term.
+        ///
+        /// The agreement terminates on a fixed date.
+        Term = b'T', "T";
+    }
+}
+
+macros::impl_attr! {
+    /// Repurchase agreement delivery.
+    pub enum RepurchaseDelivery[5] {
+        /// Delivery versus payment.
+        ///
+        /// The collateral is delivered against simultaneous payment.
+        DeliveryVersusPayment = b'D', "D";
+
+        /// Hold-in-custody.
+        ///
+        /// The collateral is held by the seller in a segregated account on behalf of the buyer.
+        HoldInCustody = b'H', "H";
+
+        /// Tri-party.
+        ///
+        /// The collateral is managed by a third-party agent on behalf of both parties.
+        TriParty = b'T', "T";
+    }
+}
+
+macros::impl_attr! {
+    /// Underlying assets for securities lending.
+    pub enum LendingUnderlying[2] {
+        /// Cash collateral.
+        Cash = b'C', "C";
+
+        /// Government bonds.
+        Government = b'G', "G";
+
+        /// Corporate bonds.
+        Corporate = b'P', "P";
+
+        /// Convertible bonds.
+        Convertible = b'T', "T";
+
+        /// Equity.
+        Equity = b'E', "E";
+
+        /// Letter of credit.
+        LetterOfCredit = b'L', "L";
+
+        /// Certificate of deposit.
+        CertificateOfDeposit = b'D', "D";
+
+        /// Warrants.
+        Warrants = b'W', "W";
+
+        /// Money market instruments.
+        MoneyMarket = b'K', "K";
+
+        /// Others (miscellaneous).
+        Other = b'M', "M";
+    }
+}
+
+macros::impl_attr! {
+    /// Securities lending termination.
+    pub enum LendingTermination[3] {
+        /// Overnight.
+        ///
+        /// The agreement terminates on the next business day.
+        Overnight = b'N', "N";
+
+        /// Open.
+        ///
+        /// The agreement has no fixed termination date and continues until either party
+        /// terminates it.
+        Open = b'O', "O";
+
+        /// Term.
+        ///
+        /// The agreement terminates on a fixed date.
+        Term = b'T', "T";
+    }
+}
+
+macros::impl_attr! {
+    /// Securities lending delivery.
+    pub enum LendingDelivery[5] {
+        /// Delivery versus payment.
+        ///
+        /// The collateral is delivered against simultaneous payment.
+        DeliveryVersusPayment = b'D', "D";
+
+        /// Free of payment.
+        ///
+        /// The collateral is delivered without a corresponding payment.
+        FreeOfPayment = b'F', "F";
+
+        /// Hold-in-custody.
+        ///
+        /// The collateral is held by the lender in a segregated account on behalf of the borrower.
+        HoldInCustody = b'H', "H";
+
+        /// Tri-party.
+        ///
+        /// The collateral is managed by a third-party agent on behalf of both parties.
+        TriParty = b'T', "T";
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CFI_LENGTH, Code};
+
+    #[yare::parameterized(
+        loan_lease_undefined = {
+            *b"LLXXXX",
+            Financing::LoanLease(LoanLease {
+                underlying: LoanLeaseUnderlying::Undefined,
+                unassigned2: NotApplicable::Undefined,
+                unassigned3: NotApplicable::Undefined,
+                delivery: LoanLeaseDelivery::Undefined,
+            })
+        },
+        repurchase = {
+            *b"LRGNXT",
+            Financing::Repurchase(Repurchase {
+                underlying: RepurchaseUnderlying::General,
+                termination: RepurchaseTermination::Overnight,
+                unassigned3: NotApplicable::Undefined,
+                delivery: RepurchaseDelivery::TriParty,
+            })
+        },
+        securities_lending = {
+            *b"LSETXF",
+            Financing::SecuritiesLending(SecuritiesLending {
+                underlying: LendingUnderlying::Equity,
+                termination: LendingTermination::Term,
+                unassigned3: NotApplicable::Undefined,
+                delivery: LendingDelivery::FreeOfPayment,
+            })
+        },
+    )]
+    fn from_bytes(src: [u8; CFI_LENGTH], expected: Financing) {
+        let financing = Financing::from_bytes(&src).expect("Could not parse code");
+        assert_eq!(expected, financing);
+        assert_eq!(src, financing.to_bytes());
+        assert_eq!(Ok(Code::Financing(expected)), Code::from_bytes(&src));
+    }
+}
@@ -0,0 +1,454 @@
+//! Forwards category support.
+
+use crate::{NotApplicable, macros};
+
+macros::impl_category! {
+    /// Forwards.
+    ///
+    /// Contracts, which are not exchange traded or listed, entered between two parties to buy or
+    /// sell the underlying asset at a specified future date at the price specified at the outset
+    /// of the contract.
+    pub enum Forward[b'J'] {
+        /// Equity.
+        ///
+        /// A forward contract where the underlying asset is an equity-linked instrument (i.e.
+        /// shares, indices, baskets, or derivatives thereon).
+        Equity(Equity) = b'E', "E";
+
+        /// Foreign exchange.
+        ///
+        /// An agreement between two parties to exchange a given amount of one currency for another
+        /// currency at an agreed rate on a specified future date.
+        Forex(Forex) = b'F', "F";
+
+        /// Credit.
+        ///
+        /// A forward contract where the underlying asset is a debt product or debt issuer.
+        Credit(Credit) = b'C', "C";
+
+        /// Rates.
+        ///
+        /// A forward contract where the underlying asset is an interest rate, such as a forward
+        /// rate agreement (FRA).
+        Rate(Rate) = b'R', "R";
+
+        /// Commodities.
+        ///
+        /// A forward contract where the underlying asset is a specified commodity, commodity index
+        /// or basket of commodities.
+        Commodity(Commodity) = b'T', "T";
+    }
+}
+
+macros::impl_group! {
+    /// Equity.
+    ///
+    /// A forward contract where the underlying asset is an equity-linked instrument (i.e. shares,
+    /// indices, baskets, or derivatives thereon).
+    pub struct Equity {
+        /// Underlying assets.
+        pub underlying: EquityUnderlying, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Return or payout trigger (method used to determine contract value).
+        pub payout: Payout, 3;
+
+        /// Delivery (indicates whether the settlement of the forward is made in cash or whether
+        /// the underlying instruments are delivered).
+        pub delivery: Delivery, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Foreign exchange.
+    ///
+    /// An agreement between two parties to exchange a given amount of one currency for another
+    /// currency at an agreed rate on a specified future date.
+    pub struct Forex {
+        /// Underlying assets.
+        pub underlying: ForexUnderlying, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Return or payout trigger (method used to determine contract value).
+        pub payout: ForexPayout, 3;
+
+        /// Delivery (indicates whether the settlement of the forward is made in cash or whether
+        /// the underlying currencies are delivered).
+        pub delivery: ForexDelivery, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Credit.
+    ///
+    /// A forward contract where the underlying asset is a debt product or debt issuer.
+    pub struct Credit {
+        /// Underlying assets.
+        pub underlying: CreditUnderlying, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Return or payout trigger (method used to determine contract value).
+        pub payout: Payout, 3;
+
+        /// Delivery (indicates whether the settlement of the forward is made in cash or whether
+        /// the underlying instruments are delivered).
+        pub delivery: Delivery, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Rates.
+    ///
+    /// A forward contract where the underlying asset is an interest rate, such as a forward rate
+    /// agreement (FRA).
+    pub struct Rate {
+        /// Underlying assets.
+        pub underlying: RateUnderlying, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Return or payout trigger (method used to determine contract value).
+        pub payout: RatePayout, 3;
+
+        /// Delivery (indicates whether the settlement of the forward is made in cash or whether
+        /// the underlying instruments are delivered).
+        pub delivery: Delivery, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Commodities.
+    ///
+    /// A forward contract where the underlying asset is a specified commodity, commodity index or
+    /// basket of commodities.
+    pub struct Commodity {
+        /// Underlying assets.
+        pub underlying: CommodityUnderlying, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Return or payout trigger (method used to determine contract value).
+        pub payout: Payout, 3;
+
+        /// Delivery (indicates whether the settlement of the forward is made in cash or whether
+        /// the underlying commodities are delivered).
+        pub delivery: CommodityDelivery, 4;
+    }
+}
+
+macros::impl_attr! {
+    /// Underlying equity assets.
+    pub enum EquityUnderlying[2] {
+        /// Single stock.
+        Stock = b'S', "S";
+
+        /// Index.
+        Index = b'I', "I";
+
+        /// Basket.
+        Basket = b'B', "B";
+
+        /// Options.
+        Option = b'O', "O";
+
+        /// Futures.
+        Future = b'F', "F";
+    }
+}
+
+macros::impl_attr! {
+    /// Underlying foreign exchange assets.
+    pub enum ForexUnderlying[2] {
+        /// Spot.
+        Spot = b'T', "T";
+
+        /// Forwards.
+        Forward = b'R', "R";
+
+        /// Futures.
+        Future = b'F', "F";
+
+        /// Vanilla options.
+        Option = b'V', "V";
+    }
+}
+
+macros::impl_attr! {
+    /// Underlying credit assets.
+    pub enum CreditUnderlying[2] {
+        /// Single name.
+        SingleName = b'A', "A";
+
+        /// Index.
+        Index = b'I', "I";
+
+        /// Basket.
+        Basket = b'B', "B";
+
+        /// CDS on a single name.
+        SingleNameCds = b'C', "C";
+
+        /// CDS on an index.
+        IndexCds = b'D', "D";
+
+        /// CDS on a basket.
+        BasketCds = b'G', "G";
+
+        /// Options.
+        Option = b'O', "O";
+
+        /// Others (miscellaneous).
+        Other = b'M', "M";
+    }
+}
+
+macros::impl_attr! {
+    /// Underlying rate assets.
+    pub enum RateUnderlying[2] {
+        /// Interest rate index.
+        Index = b'I', "I";
+
+        /// Options.
+        Option = b'O', "O";
+
+        /// Others (miscellaneous).
+        Other = b'M', "M";
+    }
+}
+
+macros::impl_attr! {
+    /// Underlying commodity assets.
+    pub enum CommodityUnderlying[2] {
+        /// Agriculture.
+        ///
+        /// Commodities which include forestry, fishing, livestock, grain, dairy, corn, cocoa,
+        /// soybeans, sugar, coffee.
+        Agriculture = b'A', "A";
+
+        /// Basket.
+        ///
+        /// A custom basket containing constituents from one or more commodities.
+        Basket = b'B', "B";
+
+        /// Freight.
+        ///
+        /// The specified commodity is a freight index route.
+        Freight = b'G', "G";
+
+        /// Index.
+        ///
+        /// An index containing constituents from one or more commodities.
+        Index = b'I', "I";
+
+        /// Energy.
+        ///
+        /// An energy-related product, or a derivative of an energy-related product, including
+        /// electricity, renewable energy, or any power/energy delivered through a utility network
+        /// or provider.
+        Energy = b'J', "J";
+
+        /// Metals.
+        ///
+        /// A precious or industrial metal, such as aluminium, copper, gold, lead, nickel,
+        /// platinum, silver, tin, zinc.
+        Metals = b'K', "K";
+
+        /// Environmental.
+        ///
+        /// Includes carbon-related, emission reduction, weather.
+        Environmental = b'N', "N";
+
+        /// Polypropylene products.
+        Polypropylene = b'P', "P";
+
+        /// Fertilizer.
+        Fertilizer = b'S', "S";
+
+        /// Paper.
+        Paper = b'T', "T";
+
+        /// Others (miscellaneous).
+        Other = b'M', "M";
+    }
+}
+
+macros::impl_attr! {
+    /// Return or payout trigger.
+    ///
+    /// Method used to determine contract value.
+    pub enum Payout[4] {
+        /// Contract for difference (CFD).
+        ///
+        /// A cash-settled forward where the parties agree to exchange on the maturity of the
+        /// contract the difference between the opening price and closing price of the underlying.
+        Cfd = b'C', "C";
+
+        /// Spread-bet.
+        ///
+        /// The payout is determined by the movement in the reference price of the underlying
+        /// instrument multiplied by an agreed amount per point movement.
+        SpreadBet = b'S', "S";
+
+        /// Forward price of underlying instrument.
+        ///
+        /// The agreed price for the underlying instrument at the specified future date.
+        ForwardPrice = b'F', "F";
+    }
+}
+
+macros::impl_attr! {
+    /// Foreign exchange return or payout trigger.
+    ///
+    /// Method used to determine contract value.
+    pub enum ForexPayout[4] {
+        /// Contract for difference (CFD).
+        Cfd = b'C', "C";
+
+        /// Spread-bet.
+        SpreadBet = b'S', "S";
+
+        /// Forward price of underlying instrument.
+        ForwardPrice = b'F', "F";
+
+        /// Rolling spot.
+        ///
+        /// An indefinitely renewed position in which no currency is actually delivered until a
+        /// party closes out its position.
+        RollingSpot = b'R', "R";
+    }
+}
+
+macros::impl_attr! {
+    /// Rate return or payout trigger.
+    ///
+    /// Method used to determine contract value.
+    pub enum RatePayout[4] {
+        /// Contract for difference (CFD).
+        Cfd = b'C', "C";
+
+        /// Spread-bet.
+        SpreadBet = b'S', "S";
+
+        /// Forward price of underlying instrument.
+        ForwardPrice = b'F', "F";
+
+        /// Forward rate agreement (FRA).
+        ///
+        /// An agreement in which one party pays a fixed interest rate and receives a floating
+        /// interest rate, with the payment based on a notional amount over a specified period.
+        ForwardRateAgreement = b'A', "A";
+    }
+}
+
+macros::impl_attr! {
+    /// Delivery.
+    ///
+    /// Indicates whether the settlement of the forward is made in cash or whether the underlying
+    /// instruments are delivered.
+    pub enum Delivery[5] {
+        /// Cash.
+        Cash = b'C', "C";
+
+        /// Physical.
+        Physical = b'P', "P";
+    }
+}
+
+macros::impl_attr! {
+    /// Foreign exchange delivery.
+    pub enum ForexDelivery[5] {
+        /// Physical (delivery of traded currencies on settlement date).
+        Physical = b'P', "P";
+
+        /// Cash.
+        Cash = b'C', "C";
+
+        /// Non-deliverable.
+        ///
+        /// The settlement amount is paid in a single currency rather than through the exchange of
+        /// the two currencies.
+        NonDeliverable = b'N', "N";
+    }
+}
+
+macros::impl_attr! {
+    /// Commodity delivery.
+    pub enum CommodityDelivery[5] {
+        /// Cash.
+        Cash = b'C', "C";
+
+        /// Physical.
+        Physical = b'P', "P";
+
+        /// Elect at settlement (determined at the time of settlement).
+        ElectAtSettlement = b'E', "E";
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CFI_LENGTH, Code};
+
+    #[yare::parameterized(
+        equity = {
+            *b"JESXCP",
+            Forward::Equity(Equity {
+                underlying: EquityUnderlying::Stock,
+                unassigned2: NotApplicable::Undefined,
+                payout: Payout::Cfd,
+                delivery: Delivery::Physical,
+            })
+        },
+        forex = {
+            *b"JFTXRN",
+            Forward::Forex(Forex {
+                underlying: ForexUnderlying::Spot,
+                unassigned2: NotApplicable::Undefined,
+                payout: ForexPayout::RollingSpot,
+                delivery: ForexDelivery::NonDeliverable,
+            })
+        },
+        credit = {
+            *b"JCDXFC",
+            Forward::Credit(Credit {
+                underlying: CreditUnderlying::IndexCds,
+                unassigned2: NotApplicable::Undefined,
+                payout: Payout::ForwardPrice,
+                delivery: Delivery::Cash,
+            })
+        },
+        rate_undefined = {
+            *b"JRXXXX",
+            Forward::Rate(Rate {
+                underlying: RateUnderlying::Undefined,
+                unassigned2: NotApplicable::Undefined,
+                payout: RatePayout::Undefined,
+                delivery: Delivery::Undefined,
+            })
+        },
+        commodity = {
+            *b"JTKXFE",
+            Forward::Commodity(Commodity {
+                underlying: CommodityUnderlying::Metals,
+                unassigned2: NotApplicable::Undefined,
+                payout: Payout::ForwardPrice,
+                delivery: CommodityDelivery::ElectAtSettlement,
+            })
+        },
+    )]
+    fn from_bytes(src: [u8; CFI_LENGTH], expected: Forward) {
+        let forward = Forward::from_bytes(&src).expect("Could not parse code");
+        assert_eq!(expected, forward);
+        assert_eq!(src, forward.to_bytes());
+        assert_eq!(Ok(Code::Forward(expected)), Code::from_bytes(&src));
+    }
+}
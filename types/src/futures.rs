@@ -8,7 +8,7 @@ macros::impl_category! {
     /// Contracts, listed on an exchange or regulated market, which obligate the buyer to receive
     /// and the seller to deliver in the future the assets specified at an agreed price. This
     /// includes forwards on regulated markets.
-    pub enum Future {
+    pub enum Future[b'F'] {
         /// Financial futures.
         ///
         /// Futures contracts based on underlying assets excluding commodities.
@@ -152,3 +152,19 @@ macros::impl_attr! {
         Other = b'M', "M";
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::CFI_LENGTH;
+
+    #[yare::parameterized(
+        financial = {*b"FFXXXX", Future::is_financial},
+        commodity = {*b"FCXXXX", Future::is_commodity},
+    )]
+    fn from_bytes_undefined(src: [u8; CFI_LENGTH], func: fn(&Future) -> bool) {
+        let future = Future::from_bytes(&src).expect("Could not parse undefined attributes");
+        assert!(func(&future));
+        assert_eq!(src, future.to_bytes());
+    }
+}
@@ -5,17 +5,48 @@
 #![no_std]
 
 pub mod civ;
+#[cfg(feature = "csv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "csv")))]
+pub mod csv;
 pub mod debt;
 pub mod equities;
+pub mod financing;
+pub mod forwards;
 pub mod futures;
+pub mod misc;
 pub mod options;
+pub mod referential;
 pub mod rights;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
+pub mod spot;
+pub mod strategies;
 pub mod swaps;
+pub mod validate;
 
+#[cfg(test)]
+mod conformance;
+mod describe;
+mod diagnose;
 mod error;
+mod list;
 mod macros;
+mod parse;
+mod pattern;
+
+pub use crate::{
+    describe::{Description, Label},
+    diagnose::{Diagnostic, Diagnostics},
+    error::{DetailedError, Error, Result},
+    parse::{Normalized, ParseOptions, Warning, Warnings},
+    pattern::{CfiPattern, PatternError},
+};
 
-pub use crate::error::{Error, Result};
+use core::{
+    fmt::{self, Display, Formatter},
+    str::{self, FromStr},
+};
 
 /// The length of a CFI code, in bytes.
 pub const CFI_LENGTH: usize = 6;
@@ -26,10 +57,19 @@ const CATEGORY_IDX: usize = 0;
 /// The byte index of the group character.
 const GROUP_IDX: usize = 1;
 
+/// Write the given CFI bytes to a formatter as a string.
+fn fmt_bytes(value: [u8; CFI_LENGTH], f: &mut Formatter<'_>) -> fmt::Result {
+    f.pad(str::from_utf8(&value).map_err(|_| fmt::Error)?)
+}
+
 /// A trait implemented by CFI code attributes used to parse a code.
 pub trait Attr: Sized {
     /// Attempt to parse the given ASCII byte as this attribute.
     ///
+    /// The byte's position within a code is not known here, so an
+    /// [`Error::InvalidAttribute`] reports position `0`. Use [`AttrPos::from_cfi_bytes()`] to
+    /// have the real position reported.
+    ///
     /// # Errors
     ///
     /// - A specific value if a given character position contains an invald value.
@@ -50,23 +90,69 @@ pub trait AttrPos<const INDEX: usize>: Sized + Attr {
             return Err(Error::InvalidLength);
         }
 
-        Self::from_code_byte(value[INDEX])
+        match Self::from_code_byte(value[INDEX]) {
+            Err(Error::InvalidAttribute(_, val)) => Err(Error::InvalidAttribute(INDEX, val)),
+            other => other,
+        }
     }
 }
 
+/// A trait implemented by CFI code attributes to describe themselves.
+pub trait AttrInfo: Attr + Copy + 'static {
+    /// The name of this attribute type.
+    const NAME: &'static str;
+
+    /// The byte positions within a CFI code at which this attribute may appear.
+    const POSITIONS: &'static [usize];
+
+    /// Every variant of this attribute, including the undefined value.
+    const VARIANTS: &'static [Self];
+
+    /// The names of every variant of this attribute, in the same order as [`Self::VARIANTS`].
+    const NAMES: &'static [&'static str];
+
+    /// The short descriptions of every variant of this attribute, in the same order as
+    /// [`Self::VARIANTS`].
+    const DESCRIPTIONS: &'static [&'static str];
+
+    /// Retrieve the CFI character of this value.
+    fn code_char(&self) -> char;
+
+    /// Retrieve the name of this value's variant.
+    #[inline]
+    fn label(&self) -> &'static str {
+        variant_entry(self, Self::NAMES)
+    }
+
+    /// Retrieve the short description of this value.
+    #[inline]
+    fn description(&self) -> &'static str {
+        variant_entry(self, Self::DESCRIPTIONS)
+    }
+}
+
+/// Find the entry for the given attribute's variant in a list ordered like its variants.
+fn variant_entry<A: AttrInfo>(attr: &A, entries: &'static [&'static str]) -> &'static str {
+    A::VARIANTS
+        .iter()
+        .zip(entries)
+        .find(|(variant, _)| variant.code_char() == attr.code_char())
+        .map_or("", |(_, entry)| entry)
+}
+
 /// A CFI group is a collection of attributes.
 pub trait CfiGroup: Sized {
     /// The type of the first attribute.
-    type Attr1: AttrPos<2>;
+    type Attr1: AttrPos<2> + AttrInfo;
 
     /// The type of the second attribute.
-    type Attr2: AttrPos<3>;
+    type Attr2: AttrPos<3> + AttrInfo;
 
     /// The type of the third attribute.
-    type Attr3: AttrPos<4>;
+    type Attr3: AttrPos<4> + AttrInfo;
 
     /// The type of the fourth attribute.
-    type Attr4: AttrPos<5>;
+    type Attr4: AttrPos<5> + AttrInfo;
 
     /// Retrieve the first attribute.
     fn attr1(&self) -> Self::Attr1;
@@ -89,6 +175,18 @@ pub trait CfiGroup: Sized {
     fn from_cfi_bytes(value: &[u8]) -> Result<Self>;
 }
 
+/// A trait implemented by CFI groups to describe their attributes.
+pub trait GroupInfo: CfiGroup {
+    /// The field names of the attributes, in code order.
+    const FIELDS: [&'static str; 4];
+
+    /// The byte positions within a CFI code of the attributes, in code order.
+    const POSITIONS: [usize; 4];
+
+    /// Describe the attributes of this value, in code order.
+    fn labels(&self) -> [Label; 4];
+}
+
 /// A hierarchical enumeration of CFI Codes.
 ///
 /// # Examples
@@ -160,20 +258,20 @@ pub enum Code {
     ///
     /// Contracts conducted on the spot market which are bought and sold for cash with immediate
     /// delivery based on market convention for the asset.
-    Spot(()) = b'I',
+    Spot(spot::Spot) = b'I',
 
     /// `J`: Forwards.
     ///
     /// Contracts, which are not exchange traded or listed, entered between two parties to buy or
     /// sell the underlying asset at a specified future date at the price specified at the outset
     /// of the contract.
-    Forward(()) = b'J',
+    Forward(forwards::Forward) = b'J',
 
     /// `K`: Strategies.
     ///
     /// This subclause defines a classification of derivative strategies. Strategies are the
     /// simultaneous trading of two or more derivative instruments.
-    Strategy(()) = b'K',
+    Strategy(strategies::Strategy) = b'K',
 
     /// `L`: Financing.
     ///
@@ -183,20 +281,54 @@ pub enum Code {
     /// Depending on the exact type of financing transaction, a simultaneous agreement to reverse
     /// the agreement may be entered into at the same time with an agreed-upon future date for the
     /// reverse transaction to take place.
-    Financing(()) = b'L',
+    Financing(financing::Financing) = b'L',
 
     /// `T`: Referential Instruments.
     ///
     /// Indicators that are used as a reference for other financial instruments.
-    Referential(()) = b'T',
+    Referential(referential::Referential) = b'T',
 
     /// `M`: Misc / Other Instruments.
     ///
     /// Financial instruments that do not fit the above categories as defined.
-    Misc(()) = b'M',
+    Misc(misc::Misc) = b'M',
 }
 
 impl Code {
+    /// The number of valid codes across every category.
+    pub const COUNT: usize = equities::Equity::COUNT
+        + debt::Debt::COUNT
+        + civ::Civ::COUNT
+        + rights::Right::COUNT
+        + options::Listed::COUNT
+        + futures::Future::COUNT
+        + swaps::Swap::COUNT
+        + options::Unlisted::COUNT
+        + spot::Spot::COUNT
+        + forwards::Forward::COUNT
+        + strategies::Strategy::COUNT
+        + financing::Financing::COUNT
+        + referential::Referential::COUNT
+        + misc::Misc::COUNT;
+
+    /// The category characters of every category.
+    pub const CATEGORIES: &[u8] = &[
+        equities::Equity::CATEGORY,
+        debt::Debt::CATEGORY,
+        civ::Civ::CATEGORY,
+        rights::Right::CATEGORY,
+        options::Listed::CATEGORY,
+        futures::Future::CATEGORY,
+        swaps::Swap::CATEGORY,
+        options::Unlisted::CATEGORY,
+        spot::Spot::CATEGORY,
+        forwards::Forward::CATEGORY,
+        strategies::Strategy::CATEGORY,
+        financing::Financing::CATEGORY,
+        referential::Referential::CATEGORY,
+        misc::Misc::CATEGORY,
+    ];
+
     /// Whether this instance is an equity.
     #[inline]
     #[must_use]
@@ -257,42 +389,42 @@ impl Code {
     #[inline]
     #[must_use]
     pub const fn is_spot(&self) -> bool {
-        matches!(self, Self::Spot(()))
+        matches!(self, Self::Spot(_))
     }
 
     /// Whether this instance is a forward contract.
     #[inline]
     #[must_use]
     pub const fn is_forward(&self) -> bool {
-        matches!(self, Self::Forward(()))
+        matches!(self, Self::Forward(_))
     }
 
     /// Whether this instance is a derivative strategy.
     #[inline]
     #[must_use]
     pub const fn is_strategy(&self) -> bool {
-        matches!(self, Self::Strategy(()))
+        matches!(self, Self::Strategy(_))
     }
 
     /// Whether this instance is a financing agreement.
     #[inline]
     #[must_use]
     pub const fn is_financing(&self) -> bool {
-        matches!(self, Self::Financing(()))
+        matches!(self, Self::Financing(_))
     }
 
     /// Whether this instance is a referential instrument.
     #[inline]
     #[must_use]
     pub const fn is_referential(&self) -> bool {
-        matches!(self, Self::Referential(()))
+        matches!(self, Self::Referential(_))
     }
 
     /// Whether this instance does not fit the above categories.
     #[inline]
     #[must_use]
     pub const fn is_misc(&self) -> bool {
-        matches!(self, Self::Misc(()))
+        matches!(self, Self::Misc(_))
     }
 
     /// Parse the given byte slice into a code.
@@ -311,9 +443,274 @@ impl Code {
                 Ok(value) => Ok(Self::Equity(value)),
                 Err(error) => Err(error),
             },
+            b'D' => match debt::Debt::from_bytes(src) {
+                Ok(value) => Ok(Self::Debt(value)),
+                Err(error) => Err(error),
+            },
+            b'C' => match civ::Civ::from_bytes(src) {
+                Ok(value) => Ok(Self::Civ(value)),
+                Err(error) => Err(error),
+            },
+            b'R' => match rights::Right::from_bytes(src) {
+                Ok(value) => Ok(Self::Right(value)),
+                Err(error) => Err(error),
+            },
+            b'O' => match options::Listed::from_bytes(src) {
+                Ok(value) => Ok(Self::ListedOption(value)),
+                Err(error) => Err(error),
+            },
+            b'F' => match futures::Future::from_bytes(src) {
+                Ok(value) => Ok(Self::Future(value)),
+                Err(error) => Err(error),
+            },
+            b'S' => match swaps::Swap::from_bytes(src) {
+                Ok(value) => Ok(Self::Swap(value)),
+                Err(error) => Err(error),
+            },
+            b'H' => match options::Unlisted::from_bytes(src) {
+                Ok(value) => Ok(Self::UnlistedOption(value)),
+                Err(error) => Err(error),
+            },
+            b'I' => match spot::Spot::from_bytes(src) {
+                Ok(value) => Ok(Self::Spot(value)),
+                Err(error) => Err(error),
+            },
+            b'J' => match forwards::Forward::from_bytes(src) {
+                Ok(value) => Ok(Self::Forward(value)),
+                Err(error) => Err(error),
+            },
+            b'K' => match strategies::Strategy::from_bytes(src) {
+                Ok(value) => Ok(Self::Strategy(value)),
+                Err(error) => Err(error),
+            },
+            b'L' => match financing::Financing::from_bytes(src) {
+                Ok(value) => Ok(Self::Financing(value)),
+                Err(error) => Err(error),
+            },
+            b'T' => match referential::Referential::from_bytes(src) {
+                Ok(value) => Ok(Self::Referential(value)),
+                Err(error) => Err(error),
+            },
+            b'M' => match misc::Misc::from_bytes(src) {
+                Ok(value) => Ok(Self::Misc(value)),
+                Err(error) => Err(error),
+            },
             other => Err(Error::InvalidCategory(other as char)),
         }
     }
+
+    /// Parse the given byte slice into a code, explaining any error found.
+    ///
+    /// This reports the same error as [`Code::from_bytes()`], along with the category, group,
+    /// field, and valid characters at the position of the error. An [`Error::InvalidLength`] has no
+    /// position, so it is reported without context.
+    ///
+    /// # Errors
+    ///
+    /// - [`DetailedError`] wrapping the error [`Code::from_bytes()`] would report.
+    #[inline]
+    pub const fn from_bytes_detailed(src: &[u8]) -> Result<Self, DetailedError> {
+        match Self::from_bytes(src) {
+            Ok(code) => Ok(code),
+            Err(error) => Err(DetailedError::new(error, src)),
+        }
+    }
+
+    /// Check every character of the given byte slice, reporting each one which is invalid.
+    ///
+    /// Unlike [`Code::from_bytes()`], this does not stop at the first invalid character. The
+    /// group can only be checked for a valid category, and attributes for a valid group. The
+    /// diagnostics of a valid code are empty.
+    #[inline]
+    #[must_use]
+    pub const fn diagnose(src: &[u8]) -> Diagnostics {
+        let mut diagnostics = Diagnostics::new(src.len() != CFI_LENGTH);
+        if src.is_empty() {
+            return diagnostics;
+        }
+
+        match src[CATEGORY_IDX] {
+            equities::Equity::CATEGORY => equities::Equity::diagnose(src, &mut diagnostics),
+            debt::Debt::CATEGORY => debt::Debt::diagnose(src, &mut diagnostics),
+            civ::Civ::CATEGORY => civ::Civ::diagnose(src, &mut diagnostics),
+            rights::Right::CATEGORY => rights::Right::diagnose(src, &mut diagnostics),
+            options::Listed::CATEGORY => options::Listed::diagnose(src, &mut diagnostics),
+            futures::Future::CATEGORY => futures::Future::diagnose(src, &mut diagnostics),
+            swaps::Swap::CATEGORY => swaps::Swap::diagnose(src, &mut diagnostics),
+            options::Unlisted::CATEGORY => options::Unlisted::diagnose(src, &mut diagnostics),
+            spot::Spot::CATEGORY => spot::Spot::diagnose(src, &mut diagnostics),
+            forwards::Forward::CATEGORY => forwards::Forward::diagnose(src, &mut diagnostics),
+            strategies::Strategy::CATEGORY => strategies::Strategy::diagnose(src, &mut diagnostics),
+            financing::Financing::CATEGORY => financing::Financing::diagnose(src, &mut diagnostics),
+            referential::Referential::CATEGORY => {
+                referential::Referential::diagnose(src, &mut diagnostics);
+            }
+            misc::Misc::CATEGORY => misc::Misc::diagnose(src, &mut diagnostics),
+            other => diagnostics.push(Diagnostic {
+                idx: CATEGORY_IDX,
+                value: other as char,
+                field: "category",
+                allowed: Self::CATEGORIES,
+            }),
+        }
+
+        diagnostics
+    }
+
+    /// Parse the given byte slice into a code, normalizing common formatting problems.
+    ///
+    /// This applies every normalization in [`ParseOptions::LENIENT`], and reports which were
+    /// needed as [`Warnings`].
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidLength`] if the normalized input is not [`CFI_LENGTH`] bytes.
+    /// - A more specific error if the normalized input is not a valid code.
+    #[inline]
+    pub const fn parse_lenient(src: &[u8]) -> Result<Normalized> {
+        ParseOptions::LENIENT.parse(src)
+    }
+
+    /// Encode this code as its six ASCII bytes.
+    #[inline]
+    #[must_use]
+    pub const fn to_bytes(&self) -> [u8; CFI_LENGTH] {
+        match self {
+            Self::Equity(value) => value.to_bytes(),
+            Self::Debt(value) => value.to_bytes(),
+            Self::Civ(value) => value.to_bytes(),
+            Self::Right(value) => value.to_bytes(),
+            Self::ListedOption(value) => value.to_bytes(),
+            Self::Future(value) => value.to_bytes(),
+            Self::Swap(value) => value.to_bytes(),
+            Self::UnlistedOption(value) => value.to_bytes(),
+            Self::Spot(value) => value.to_bytes(),
+            Self::Forward(value) => value.to_bytes(),
+            Self::Strategy(value) => value.to_bytes(),
+            Self::Financing(value) => value.to_bytes(),
+            Self::Referential(value) => value.to_bytes(),
+            Self::Misc(value) => value.to_bytes(),
+        }
+    }
+
+    /// Describe the category, group, and attributes of this code.
+    #[inline]
+    #[must_use]
+    pub const fn describe(&self) -> Description {
+        match self {
+            Self::Equity(value) => value.describe(),
+            Self::Debt(value) => value.describe(),
+            Self::Civ(value) => value.describe(),
+            Self::Right(value) => value.describe(),
+            Self::ListedOption(value) => value.describe(),
+            Self::Future(value) => value.describe(),
+            Self::Swap(value) => value.describe(),
+            Self::UnlistedOption(value) => value.describe(),
+            Self::Spot(value) => value.describe(),
+            Self::Forward(value) => value.describe(),
+            Self::Strategy(value) => value.describe(),
+            Self::Financing(value) => value.describe(),
+            Self::Referential(value) => value.describe(),
+            Self::Misc(value) => value.describe(),
+        }
+    }
+
+    /// Iterate over every valid code.
+    #[inline]
+    pub fn iter_all() -> impl Iterator<Item = Self> {
+        equities::Equity::iter()
+            .map(Self::Equity)
+            .chain(debt::Debt::iter().map(Self::Debt))
+            .chain(civ::Civ::iter().map(Self::Civ))
+            .chain(rights::Right::iter().map(Self::Right))
+            .chain(options::Listed::iter().map(Self::ListedOption))
+            .chain(futures::Future::iter().map(Self::Future))
+            .chain(swaps::Swap::iter().map(Self::Swap))
+            .chain(options::Unlisted::iter().map(Self::UnlistedOption))
+            .chain(spot::Spot::iter().map(Self::Spot))
+            .chain(forwards::Forward::iter().map(Self::Forward))
+            .chain(strategies::Strategy::iter().map(Self::Strategy))
+            .chain(financing::Financing::iter().map(Self::Financing))
+            .chain(referential::Referential::iter().map(Self::Referential))
+            .chain(misc::Misc::iter().map(Self::Misc))
+    }
+}
+
+impl Display for Code {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_bytes(self.to_bytes(), f)
+    }
+}
+
+impl FromStr for Code {
+    type Err = Error;
+
+    #[inline]
+    fn from_str(value: &str) -> Result<Self> {
+        Self::from_bytes(value.as_bytes())
+    }
+}
+
+impl TryFrom<&str> for Code {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self> {
+        Self::from_bytes(value.as_bytes())
+    }
+}
+
+impl TryFrom<&[u8]> for Code {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self> {
+        Self::from_bytes(value)
+    }
+}
+
+impl TryFrom<[u8; CFI_LENGTH]> for Code {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: [u8; CFI_LENGTH]) -> Result<Self> {
+        Self::from_bytes(&value)
+    }
+}
+
+/// Parse a CFI code string literal into a [`Code`] at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso10962_types::{Code, cfi};
+///
+/// const COMMON: Code = cfi!("ESVUFR");
+///
+/// assert!(COMMON.is_equity());
+/// assert_eq!(*b"ESVUFR", COMMON.to_bytes());
+/// ```
+///
+/// Invalid codes fail to compile:
+///
+/// ```rust,compile_fail
+/// use iso10962_types::{Code, cfi};
+///
+/// const INVALID: Code = cfi!("ESVUFZ");
+/// ```
+#[macro_export]
+macro_rules! cfi {
+    ($code:literal) => {
+        const {
+            match $crate::Code::from_bytes($code.as_bytes()) {
+                ::core::result::Result::Ok(code) => code,
+                ::core::result::Result::Err(_) => {
+                    ::core::panic!(::core::concat!("invalid CFI code: ", $code))
+                }
+            }
+        }
+    };
 }
 
 macros::impl_attr! {
@@ -344,7 +741,15 @@ macros::impl_attr! {
 
 #[cfg(test)]
 mod test {
+    extern crate alloc;
+
     use super::*;
+    use alloc::{
+        collections::{BTreeMap, BTreeSet},
+        format,
+        vec::Vec,
+    };
+    use core::fmt::Debug;
 
     #[yare::parameterized(
         bearer = {Form::Bearer, Form::is_bearer},
@@ -355,6 +760,624 @@ mod test {
     fn form_is(form: Form, func: fn(&Form) -> bool) {
         assert!(func(&form));
     }
+
+    #[yare::parameterized(
+        equity_common = {b"ESVUFR", |c| matches!(c, Code::Equity(equities::Equity::Common(_)))},
+        equity_preferred = {b"EPNRFR", |c| matches!(c, Code::Equity(equities::Equity::Preferred(_)))},
+        equity_convertible = {b"ECVUFR", |c| matches!(c, Code::Equity(equities::Equity::Convertible(_)))},
+        equity_preferred_convertible = {b"EFNRCR", |c| matches!(c, Code::Equity(equities::Equity::PreferedConvertible(_)))},
+        equity_llp_unit = {b"ELNUFR", |c| matches!(c, Code::Equity(equities::Equity::LlpUnit(_)))},
+        equity_depository_receipt = {b"EDSNFR", |c| matches!(c, Code::Equity(equities::Equity::DepositoryReceipt(_)))},
+        equity_structured = {b"EYAYFS", |c| matches!(c, Code::Equity(equities::Equity::Structured(_)))},
+        equity_other = {b"EMXXXB", |c| matches!(c, Code::Equity(equities::Equity::Other(_)))},
+        debt_bond = {b"DBFUFR", |c| matches!(c, Code::Debt(debt::Debt::Bond(_)))},
+        debt_convertible = {b"DCFSFR", |c| matches!(c, Code::Debt(debt::Debt::Convertible(_)))},
+        debt_warrant_attached = {b"DWFUFR", |c| matches!(c, Code::Debt(debt::Debt::WarrantAttached(_)))},
+        debt_medium_term = {b"DTFUFR", |c| matches!(c, Code::Debt(debt::Debt::MediumTerm(_)))},
+        debt_money_market = {b"DYFTXB", |c| matches!(c, Code::Debt(debt::Debt::MoneyMarket(_)))},
+        debt_protected_structured = {b"DSAFFB", |c| matches!(c, Code::Debt(debt::Debt::ProtectedStructured(_)))},
+        debt_unprotected_structured = {b"DEAYRS", |c| matches!(c, Code::Debt(debt::Debt::UnprotectedStructured(_)))},
+        debt_mortgage_backed = {b"DGVGFR", |c| matches!(c, Code::Debt(debt::Debt::MortgageBacked(_)))},
+        debt_asset_backed = {b"DAFSFR", |c| matches!(c, Code::Debt(debt::Debt::AssetBacked(_)))},
+        debt_municipal = {b"DNFTFR", |c| matches!(c, Code::Debt(debt::Debt::Municipal(_)))},
+        debt_depository = {b"DDBFUF", |c| matches!(c, Code::Debt(debt::Debt::Depository(_)))},
+        debt_other = {b"DMBXXB", |c| matches!(c, Code::Debt(debt::Debt::Other(_)))},
+        civ_standard = {b"CIOIES", |c| matches!(c, Code::Civ(civ::Civ::Standard(_)))},
+        civ_hedge = {b"CHDXXX", |c| matches!(c, Code::Civ(civ::Civ::Hedge(_)))},
+        civ_reit = {b"CBCIXS", |c| matches!(c, Code::Civ(civ::Civ::Reit(_)))},
+        civ_etf = {b"CEOIES", |c| matches!(c, Code::Civ(civ::Civ::Etf(_)))},
+        civ_pension = {b"CSOBRS", |c| matches!(c, Code::Civ(civ::Civ::Pension(_)))},
+        civ_fund_of_funds = {b"CFOIIS", |c| matches!(c, Code::Civ(civ::Civ::FundOfFunds(_)))},
+        civ_private_equity = {b"CPCGES", |c| matches!(c, Code::Civ(civ::Civ::PrivateEquity(_)))},
+        civ_other = {b"CMXXXS", |c| matches!(c, Code::Civ(civ::Civ::Other(_)))},
+        right_allotment = {b"RAXXXB", |c| matches!(c, Code::Right(rights::Right::Allotment(_)))},
+        right_subscription = {b"RSSXXB", |c| matches!(c, Code::Right(rights::Right::Subscription(_)))},
+        right_purchase = {b"RPSXXB", |c| matches!(c, Code::Right(rights::Right::Purchase(_)))},
+        right_warrant = {b"RWSTCE", |c| matches!(c, Code::Right(rights::Right::Warrant(_)))},
+        right_mini_future = {b"RFSTCA", |c| matches!(c, Code::Right(rights::Right::MiniFuture(_)))},
+        right_depository = {b"RDAXXB", |c| matches!(c, Code::Right(rights::Right::DepositoryReceipt(_)))},
+        right_other = {b"RMXXXX", |c| matches!(c, Code::Right(rights::Right::Other(_)))},
+        listed_option_call = {b"OCASPS", |c| matches!(c, Code::ListedOption(options::Listed::Call(_)))},
+        listed_option_put = {b"OPEICS", |c| matches!(c, Code::ListedOption(options::Listed::Put(_)))},
+        listed_option_other = {b"OMXXXX", |c| matches!(c, Code::ListedOption(options::Listed::Other(_)))},
+        future_financial = {b"FFICSX", |c| matches!(c, Code::Future(futures::Future::Financial(_)))},
+        future_commodity = {b"FCEPSX", |c| matches!(c, Code::Future(futures::Future::Commodity(_)))},
+        swap_rate = {b"SRCCSD", |c| matches!(c, Code::Swap(swaps::Swap::Rate(_)))},
+        swap_commodity = {b"STJCXC", |c| matches!(c, Code::Swap(swaps::Swap::Commodity(_)))},
+        swap_equity = {b"SESPXC", |c| matches!(c, Code::Swap(swaps::Swap::Equity(_)))},
+        swap_credit = {b"SCUCCC", |c| matches!(c, Code::Swap(swaps::Swap::Credit(_)))},
+        swap_forex = {b"SFAXXP", |c| matches!(c, Code::Swap(swaps::Swap::Forex(_)))},
+        swap_other = {b"SMPXXC", |c| matches!(c, Code::Swap(swaps::Swap::Other(_)))},
+        unlisted_option_rate = {b"HRCAVC", |c| matches!(c, Code::UnlistedOption(options::Unlisted::Rate(_)))},
+        unlisted_option_commodity = {b"HTJAVC", |c| matches!(c, Code::UnlistedOption(options::Unlisted::Commodity(_)))},
+        unlisted_option_equity = {b"HESAVP", |c| matches!(c, Code::UnlistedOption(options::Unlisted::Equity(_)))},
+        unlisted_option_credit = {b"HCUAVC", |c| matches!(c, Code::UnlistedOption(options::Unlisted::Credit(_)))},
+        unlisted_option_forex = {b"HFTJVP", |c| matches!(c, Code::UnlistedOption(options::Unlisted::Forex(_)))},
+        unlisted_option_other = {b"HMPAVC", |c| matches!(c, Code::UnlistedOption(options::Unlisted::Other(_)))},
+        equity_undefined = {b"ESXXXX", |c| matches!(c, Code::Equity(equities::Equity::Common(_)))},
+        debt_undefined = {b"DBXXXX", |c| matches!(c, Code::Debt(debt::Debt::Bond(_)))},
+        spot_forex = {b"IFXXXP", |c| matches!(c, Code::Spot(spot::Spot::Forex(_)))},
+        spot_commodity = {b"ITJXXP", |c| matches!(c, Code::Spot(spot::Spot::Commodity(_)))},
+        forward_equity = {b"JEIXFC", |c| matches!(c, Code::Forward(forwards::Forward::Equity(_)))},
+        forward_forex = {b"JFTXFP", |c| matches!(c, Code::Forward(forwards::Forward::Forex(_)))},
+        forward_credit = {b"JCIXFC", |c| matches!(c, Code::Forward(forwards::Forward::Credit(_)))},
+        forward_rate = {b"JRIXAC", |c| matches!(c, Code::Forward(forwards::Forward::Rate(_)))},
+        forward_commodity = {b"JTJXFP", |c| matches!(c, Code::Forward(forwards::Forward::Commodity(_)))},
+        strategy_rate = {b"KRXXXX", |c| matches!(c, Code::Strategy(strategies::Strategy::Rate(_)))},
+        strategy_commodity = {b"KTXXXX", |c| matches!(c, Code::Strategy(strategies::Strategy::Commodity(_)))},
+        strategy_equity = {b"KEXXXX", |c| matches!(c, Code::Strategy(strategies::Strategy::Equity(_)))},
+        strategy_credit = {b"KCXXXX", |c| matches!(c, Code::Strategy(strategies::Strategy::Credit(_)))},
+        strategy_forex = {b"KFXXXX", |c| matches!(c, Code::Strategy(strategies::Strategy::Forex(_)))},
+        strategy_mixed = {b"KYXXXX", |c| matches!(c, Code::Strategy(strategies::Strategy::Mixed(_)))},
+        strategy_other = {b"KMXXXX", |c| matches!(c, Code::Strategy(strategies::Strategy::Other(_)))},
+        financing_loan_lease = {b"LLJXXP", |c| matches!(c, Code::Financing(financing::Financing::LoanLease(_)))},
+        financing_repurchase = {b"LRGTXD", |c| matches!(c, Code::Financing(financing::Financing::Repurchase(_)))},
+        financing_securities_lending = {b"LSGTXD", |c| matches!(c, Code::Financing(financing::Financing::SecuritiesLending(_)))},
+        referential_currency = {b"TCNXXX", |c| matches!(c, Code::Referential(referential::Referential::Currency(_)))},
+        referential_commodity = {b"TTEXXX", |c| matches!(c, Code::Referential(referential::Referential::Commodity(_)))},
+        referential_rate = {b"TRNDXX", |c| matches!(c, Code::Referential(referential::Referential::Rate(_)))},
+        referential_index = {b"TIECPX", |c| matches!(c, Code::Referential(referential::Referential::Index(_)))},
+        referential_basket = {b"TBEXXX", |c| matches!(c, Code::Referential(referential::Referential::Basket(_)))},
+        referential_stock_dividend = {b"TDSXXX", |c| matches!(c, Code::Referential(referential::Referential::StockDividend(_)))},
+        referential_other = {b"TMXXXX", |c| matches!(c, Code::Referential(referential::Referential::Other(_)))},
+        misc_combined = {b"MCSUXR", |c| matches!(c, Code::Misc(misc::Misc::Combined(_)))},
+        misc_other_asset = {b"MMRXXX", |c| matches!(c, Code::Misc(misc::Misc::OtherAsset(_)))},
+    )]
+    fn from_bytes_group(src: &[u8], func: fn(&Code) -> bool) {
+        let code = Code::from_bytes(src).expect("Could not parse code");
+        assert!(func(&code));
+    }
+
+    #[yare::parameterized(
+        short = {b"ESVUF", Error::InvalidLength},
+        long = {b"ESVUFRX", Error::InvalidLength},
+        category = {b"ZSVUFR", Error::InvalidCategory('Z')},
+        group = {b"DZFUFR", Error::InvalidGroup('Z')},
+        attribute = {b"DBFUFZ", Error::InvalidAttribute(5, 'Z')},
+        spot_group = {b"IZXXXP", Error::InvalidGroup('Z')},
+        spot_commodity_underlying = {b"ITZXXP", Error::InvalidAttribute(2, 'Z')},
+        forward_group = {b"JZTXFP", Error::InvalidGroup('Z')},
+        forward_forex_underlying = {b"JFZXFP", Error::InvalidAttribute(2, 'Z')},
+        strategy_group = {b"KZXXXX", Error::InvalidGroup('Z')},
+        strategy_rate_unassigned = {b"KRZXXX", Error::InvalidAttribute(2, 'Z')},
+        financing_group = {b"LZGTXD", Error::InvalidGroup('Z')},
+        financing_repurchase_termination = {b"LRGZXD", Error::InvalidAttribute(3, 'Z')},
+        referential_group = {b"TZNXXX", Error::InvalidGroup('Z')},
+        referential_index_return = {b"TIECZX", Error::InvalidAttribute(4, 'Z')},
+        misc_group = {b"MZSUXR", Error::InvalidGroup('Z')},
+        misc_combined_ownership = {b"MCSZXR", Error::InvalidAttribute(3, 'Z')},
+    )]
+    fn from_bytes_error(src: &[u8], expected: Error) {
+        assert_eq!(Err(expected), Code::from_bytes(src));
+    }
+
+    #[yare::parameterized(
+        equity_common = {*b"ESVUFR"},
+        equity_preferred = {*b"EPNRFR"},
+        equity_convertible = {*b"ECVUFR"},
+        equity_preferred_convertible = {*b"EFNRCR"},
+        equity_llp_unit = {*b"ELNUFR"},
+        equity_depository_receipt = {*b"EDSNFR"},
+        equity_structured = {*b"EYAYFS"},
+        equity_other = {*b"EMXXXB"},
+        debt_bond = {*b"DBFUFR"},
+        debt_convertible = {*b"DCFSFR"},
+        debt_warrant_attached = {*b"DWFUFR"},
+        debt_medium_term = {*b"DTFUFR"},
+        debt_money_market = {*b"DYFTXB"},
+        debt_protected_structured = {*b"DSAFFB"},
+        debt_unprotected_structured = {*b"DEAYRS"},
+        debt_mortgage_backed = {*b"DGVGFR"},
+        debt_asset_backed = {*b"DAFSFR"},
+        debt_municipal = {*b"DNFTFR"},
+        debt_depository = {*b"DDBFUF"},
+        debt_other = {*b"DMBXXB"},
+        civ_standard = {*b"CIOIES"},
+        civ_hedge = {*b"CHDXXX"},
+        civ_reit = {*b"CBCIXS"},
+        civ_etf = {*b"CEOIES"},
+        civ_pension = {*b"CSOBRS"},
+        civ_fund_of_funds = {*b"CFOIIS"},
+        civ_private_equity = {*b"CPCGES"},
+        civ_other = {*b"CMXXXS"},
+        right_allotment = {*b"RAXXXB"},
+        right_subscription = {*b"RSSXXB"},
+        right_purchase = {*b"RPSXXB"},
+        right_warrant = {*b"RWSTCE"},
+        right_mini_future = {*b"RFSTCA"},
+        right_depository = {*b"RDAXXB"},
+        right_other = {*b"RMXXXX"},
+        listed_option_call = {*b"OCASPS"},
+        listed_option_put = {*b"OPEICS"},
+        listed_option_other = {*b"OMXXXX"},
+        future_financial = {*b"FFICSX"},
+        future_commodity = {*b"FCEPSX"},
+        swap_rate = {*b"SRCCSD"},
+        swap_commodity = {*b"STJCXC"},
+        swap_equity = {*b"SESPXC"},
+        swap_credit = {*b"SCUCCC"},
+        swap_forex = {*b"SFAXXP"},
+        swap_other = {*b"SMPXXC"},
+        unlisted_option_rate = {*b"HRCAVC"},
+        unlisted_option_commodity = {*b"HTJAVC"},
+        unlisted_option_equity = {*b"HESAVP"},
+        unlisted_option_credit = {*b"HCUAVC"},
+        unlisted_option_forex = {*b"HFTJVP"},
+        unlisted_option_other = {*b"HMPAVC"},
+        equity_undefined = {*b"ESXXXX"},
+        debt_undefined = {*b"DBXXXX"},
+        spot_forex = {*b"IFXXXP"},
+        spot_commodity = {*b"ITJXXP"},
+        forward_equity = {*b"JEIXFC"},
+        forward_forex = {*b"JFTXFP"},
+        forward_credit = {*b"JCIXFC"},
+        forward_rate = {*b"JRIXAC"},
+        forward_commodity = {*b"JTJXFP"},
+        strategy_rate = {*b"KRXXXX"},
+        strategy_commodity = {*b"KTXXXX"},
+        strategy_equity = {*b"KEXXXX"},
+        strategy_credit = {*b"KCXXXX"},
+        strategy_forex = {*b"KFXXXX"},
+        strategy_mixed = {*b"KYXXXX"},
+        strategy_other = {*b"KMXXXX"},
+        financing_loan_lease = {*b"LLJXXP"},
+        financing_repurchase = {*b"LRGTXD"},
+        financing_securities_lending = {*b"LSGTXD"},
+        referential_currency = {*b"TCNXXX"},
+        referential_commodity = {*b"TTEXXX"},
+        referential_rate = {*b"TRNDXX"},
+        referential_index = {*b"TIECPX"},
+        referential_basket = {*b"TBEXXX"},
+        referential_stock_dividend = {*b"TDSXXX"},
+        referential_other = {*b"TMXXXX"},
+        misc_combined = {*b"MCSUXR"},
+        misc_other_asset = {*b"MMRXXX"},
+    )]
+    fn to_bytes_roundtrip(src: [u8; CFI_LENGTH]) {
+        let code = Code::from_bytes(&src).expect("Could not parse code");
+        assert_eq!(src, code.to_bytes());
+        assert_eq!(Ok(code), Code::from_bytes(&code.to_bytes()));
+    }
+
+    #[test]
+    fn group_to_bytes() {
+        let group = spot::Forex {
+            unassigned1: NotApplicable::Undefined,
+            unassigned2: NotApplicable::Undefined,
+            unassigned3: NotApplicable::Undefined,
+            delivery: spot::Delivery::Physical,
+        };
+
+        assert_eq!(*b"IFXXXP", group.to_bytes());
+        assert_eq!(*b"IFXXXP", spot::Spot::Forex(group).to_bytes());
+        assert_eq!(*b"IFXXXP", Code::Spot(spot::Spot::Forex(group)).to_bytes());
+    }
+
+    #[yare::parameterized(
+        equity_common = {"ESVUFR"},
+        debt_bond = {"DBFUFR"},
+        civ_etf = {"CEOIES"},
+        right_warrant = {"RWSTCE"},
+        listed_option_call = {"OCASPS"},
+        swap_rate = {"SRCCSD"},
+        unlisted_option_rate = {"HRCAVC"},
+    )]
+    fn display_from_str_roundtrip(src: &str) {
+        let code = src.parse::<Code>().expect("Could not parse code");
+        assert_eq!(src, format!("{code}"));
+        assert_eq!(Ok(code), Code::try_from(src));
+        assert_eq!(Ok(code), Code::try_from(src.as_bytes()));
+        assert_eq!(
+            Ok(code),
+            Code::try_from(<[u8; CFI_LENGTH]>::try_from(src.as_bytes()).expect("Bad length"))
+        );
+    }
+
+    #[yare::parameterized(
+        short = {"ESVUF", Error::InvalidLength},
+        long = {"ESVUFRX", Error::InvalidLength},
+        category = {"ZSVUFR", Error::InvalidCategory('Z')},
+        group = {"DZFUFR", Error::InvalidGroup('Z')},
+        attribute = {"DBFUFZ", Error::InvalidAttribute(5, 'Z')},
+    )]
+    fn from_str_error(src: &str, expected: Error) {
+        assert_eq!(Err(expected), src.parse::<Code>());
+        assert_eq!(Err(expected), Code::try_from(src));
+    }
+
+    #[test]
+    fn display_padding() {
+        let code = Code::from_bytes(b"ESVUFR").expect("Could not parse code");
+        assert_eq!("ESVUFR  ", format!("{code:<8}"));
+        assert_eq!("  ESVUFR", format!("{code:>8}"));
+    }
+
+    #[test]
+    fn category_display_from_str() {
+        let equity = "ESVUFR"
+            .parse::<equities::Equity>()
+            .expect("Could not parse equity");
+        assert_eq!("ESVUFR", format!("{equity}"));
+        assert_eq!(Ok(equity), equities::Equity::try_from(*b"ESVUFR"));
+        assert_eq!(
+            Err(Error::InvalidCategory('D')),
+            "DBFUFR".parse::<equities::Equity>()
+        );
+    }
+
+    #[test]
+    fn iter_all() {
+        let mut seen = BTreeSet::new();
+        for code in Code::iter_all() {
+            assert_eq!(Ok(code), Code::from_bytes(&code.to_bytes()));
+            assert!(seen.insert(code.to_bytes()), "{code}");
+        }
+
+        assert_eq!(Code::COUNT, seen.len());
+    }
+
+    #[test]
+    fn iter_group() {
+        assert_eq!(5, Form::ALL.len());
+        assert_eq!(5 * 3 * 4 * 5, equities::Common::COUNT);
+        assert_eq!(equities::Common::COUNT, equities::Common::iter().len());
+        assert!(equities::Common::iter().all(|group| group.to_bytes()[0..2] == *b"ES"));
+        assert_eq!(2, spot::Forex::COUNT);
+        assert_eq!(9 * 2, spot::Commodity::COUNT);
+        assert_eq!(2, spot::Spot::iter().filter(spot::Spot::is_forex).count());
+        assert_eq!(
+            18,
+            spot::Spot::iter().filter(spot::Spot::is_commodity).count()
+        );
+        assert_eq!(2 + 18, spot::Spot::COUNT);
+    }
+
+    #[test]
+    fn describe() {
+        let description = cfi!("ESVUFR").describe();
+        assert_eq!(
+            "Equities / Common/ordinary shares / Voting / Free / Fully paid / Registered",
+            format!("{description}")
+        );
+        assert_eq!(
+            Label {
+                field: "voting_right",
+                name: "Voting",
+                description: "Voting",
+            },
+            description.attributes[0]
+        );
+
+        let description = cfi!("IFXXXP").describe();
+        assert_eq!("Spot", description.category.description);
+        assert_eq!("Forex", description.group.name);
+        assert_eq!(
+            "Not applicable/undefined",
+            description.attributes[0].description
+        );
+        assert_eq!("Physical", description.attributes[3].description);
+    }
+
+    #[yare::parameterized(
+        protected_structured = {"DSAFFB", "Debt instruments", "Structured products (with capital protection)"},
+        unprotected_structured = {"DEAYRS", "Debt instruments", "Structured products (without capital protection)"},
+        civ_standard = {"CIOIES", "Collective Investment Vehicles", "Standard (vanilla) investment funds/mutual funds"},
+        right_allotment = {"RAXXXB", "Entitlement (rights)", "Allotment (bonus) rights"},
+    )]
+    fn describe_title(src: &str, category: &str, group: &str) {
+        let description = Code::from_str(src)
+            .expect("Could not parse code")
+            .describe();
+        assert_eq!(category, description.category.description);
+        assert_eq!(group, description.group.description);
+    }
+
+    #[test]
+    fn describe_all() {
+        let mut groups = BTreeMap::new();
+        for code in Code::iter_all() {
+            let description = code.describe();
+            assert!(
+                description
+                    .iter()
+                    .all(|label| !label.name.is_empty() && !label.description.is_empty()),
+                "{code}"
+            );
+            assert!(
+                format!("{code:?}").starts_with(&format!("{}(", description.category.name)),
+                "{code}"
+            );
+
+            let bytes = code.to_bytes();
+            let group = groups
+                .entry((bytes[CATEGORY_IDX], description.group.description))
+                .or_insert(bytes[GROUP_IDX]);
+            assert_eq!(bytes[GROUP_IDX], *group, "{code}");
+        }
+    }
+
+    /// Render the given group through its introspection traits only.
+    fn check_group_info<G: GroupInfo>(group: &G, src: [u8; CFI_LENGTH]) {
+        let chars = [
+            group.attr1().code_char(),
+            group.attr2().code_char(),
+            group.attr3().code_char(),
+            group.attr4().code_char(),
+        ];
+
+        for ((label, field), (pos, value)) in group
+            .labels()
+            .iter()
+            .zip(G::FIELDS)
+            .zip(G::POSITIONS.into_iter().zip(chars))
+        {
+            assert_eq!(field, label.field);
+            assert_eq!(src[pos] as char, value);
+        }
+
+        assert!(G::Attr1::POSITIONS.contains(&G::POSITIONS[0]));
+        assert!(G::Attr2::POSITIONS.contains(&G::POSITIONS[1]));
+        assert!(G::Attr3::POSITIONS.contains(&G::POSITIONS[2]));
+        assert!(G::Attr4::POSITIONS.contains(&G::POSITIONS[3]));
+    }
+
+    #[test]
+    fn group_info() {
+        let Code::Equity(equities::Equity::Common(common)) = cfi!("ESVUFR") else {
+            panic!("Wrong group");
+        };
+        check_group_info(&common, *b"ESVUFR");
+        assert_eq!(
+            ["voting_right", "ownership", "payment_status", "form"],
+            equities::Common::FIELDS
+        );
+
+        let Code::Spot(spot::Spot::Commodity(commodity)) = cfi!("ITJXXP") else {
+            panic!("Wrong group");
+        };
+        check_group_info(&commodity, *b"ITJXXP");
+
+        let Code::Debt(debt::Debt::Depository(depository)) = cfi!("DDBFUF") else {
+            panic!("Wrong group");
+        };
+        check_group_info(&depository, *b"DDBFUF");
+    }
+
+    #[test]
+    fn attr_info() {
+        assert_eq!("Form", <Form as AttrInfo>::NAME);
+        assert_eq!(&[5], <Form as AttrInfo>::POSITIONS);
+        assert_eq!(Form::ALL, <Form as AttrInfo>::VARIANTS);
+        assert_eq!(&[2, 3, 4, 5], <NotApplicable as AttrInfo>::POSITIONS);
+        assert_eq!('R', Form::Registered.code_char());
+        assert_eq!("Registered", Form::Registered.label());
+        assert_eq!("Registered", AttrInfo::description(&Form::Registered));
+        assert_eq!(Form::NAMES, <Form as AttrInfo>::NAMES);
+        assert_eq!(Form::DESCRIPTIONS, <Form as AttrInfo>::DESCRIPTIONS);
+        assert_eq!('X', Form::Undefined.code_char());
+        assert_eq!("Undefined", AttrInfo::label(&Form::Undefined));
+        assert_eq!(
+            "Not applicable/undefined",
+            AttrInfo::description(&Form::Undefined)
+        );
+    }
+
+    #[test]
+    fn cfi_macro() {
+        const BOND: Code = cfi!("DBFUFR");
+
+        assert_eq!(Ok(BOND), Code::from_bytes(b"DBFUFR"));
+        assert_eq!(Ok(cfi!("IFXXXP")), Code::from_bytes(b"IFXXXP"));
+    }
+
+    #[test]
+    fn cfi_macro_shadowed_prelude() {
+        /// A type shadowing the prelude's `Ok`.
+        #[expect(dead_code, reason = "Only present to shadow the prelude")]
+        struct Ok;
+
+        /// A type shadowing the prelude's `Err`.
+        #[expect(dead_code, reason = "Only present to shadow the prelude")]
+        struct Err;
+
+        assert_eq!(Code::from_bytes(b"DBFUFR").ok(), Some(cfi!("DBFUFR")));
+    }
+
+    #[test]
+    fn not_applicable_from_byte() {
+        assert_eq!(Ok(NotApplicable::Undefined), NotApplicable::from_byte(b'X'));
+        assert_eq!(
+            Err(Error::InvalidAttribute(0, 'A')),
+            NotApplicable::from_byte(b'A')
+        );
+    }
+
+    #[yare::parameterized(
+        bearer = {Form::Bearer, b'B'},
+        registered = {Form::Registered, b'R'},
+        bearer_registered = {Form::BearerRegistered, b'N'},
+        other = {Form::Other, b'M'},
+        undefined = {Form::Undefined, b'X'},
+    )]
+    fn form_to_byte(form: Form, expected: u8) {
+        assert_eq!(expected, form.to_byte());
+    }
+
+    /// A check run against the type of every group.
+    pub(crate) trait GroupVisitor {
+        /// Check the group type with the given category and group characters.
+        fn visit<G: GroupInfo + Debug>(&mut self, category: u8, group: u8);
+    }
+
+    /// Run the given visitor against the type of every group in every category.
+    fn visit_all_groups<V: GroupVisitor>(visitor: &mut V) {
+        equities::Equity::visit_groups(visitor);
+        debt::Debt::visit_groups(visitor);
+        civ::Civ::visit_groups(visitor);
+        rights::Right::visit_groups(visitor);
+        options::Listed::visit_groups(visitor);
+        futures::Future::visit_groups(visitor);
+        swaps::Swap::visit_groups(visitor);
+        options::Unlisted::visit_groups(visitor);
+        spot::Spot::visit_groups(visitor);
+        forwards::Forward::visit_groups(visitor);
+        strategies::Strategy::visit_groups(visitor);
+        financing::Financing::visit_groups(visitor);
+        referential::Referential::visit_groups(visitor);
+        misc::Misc::visit_groups(visitor);
+    }
+
+    /// The number of distinct groups across every category.
+    fn group_count() -> usize {
+        Code::iter_all()
+            .map(|code| [code.to_bytes()[CATEGORY_IDX], code.to_bytes()[GROUP_IDX]])
+            .collect::<BTreeSet<_>>()
+            .len()
+    }
+
+    /// Check every byte at the given position through the attribute's positional trait.
+    fn check_attr_pos<A: AttrPos<INDEX> + AttrInfo, const INDEX: usize>(base: [u8; CFI_LENGTH]) {
+        for value in 0..=u8::MAX {
+            let mut src = base;
+            src[INDEX] = value;
+
+            let result = <A as AttrPos<INDEX>>::from_cfi_bytes(&src);
+            if A::VARIANTS
+                .iter()
+                .any(|attr| attr.code_char() == char::from(value))
+            {
+                let attr = result.expect("Valid attribute was rejected");
+                assert_eq!(char::from(value), attr.code_char());
+            } else {
+                assert_eq!(
+                    Err(Error::InvalidAttribute(INDEX, char::from(value))),
+                    result.map(|attr| attr.code_char())
+                );
+            }
+        }
+    }
+
+    /// Parses every group through the [`CfiGroup`] and [`AttrPos`] traits.
+    #[derive(Debug, Default)]
+    struct TraitParser {
+        /// The number of groups visited.
+        groups: usize,
+    }
+
+    impl GroupVisitor for TraitParser {
+        fn visit<G: GroupInfo + Debug>(&mut self, category: u8, group: u8) {
+            self.groups += 1;
+            let base = [category, group, b'X', b'X', b'X', b'X'];
+
+            check_attr_pos::<G::Attr1, 2>(base);
+            check_attr_pos::<G::Attr2, 3>(base);
+            check_attr_pos::<G::Attr3, 4>(base);
+            check_attr_pos::<G::Attr4, 5>(base);
+
+            assert_eq!(
+                Err(Error::InvalidLength),
+                G::from_cfi_bytes(&base[..5]).map(|_| ())
+            );
+
+            for pos in G::POSITIONS {
+                for value in 0..=u8::MAX {
+                    let mut src = base;
+                    src[pos] = value;
+
+                    let expected = Code::from_bytes(&src);
+                    let result = G::from_cfi_bytes(&src);
+                    match (expected, result) {
+                        (Ok(_), Ok(parsed)) => {
+                            let chars = [
+                                parsed.attr1().code_char(),
+                                parsed.attr2().code_char(),
+                                parsed.attr3().code_char(),
+                                parsed.attr4().code_char(),
+                            ];
+                            assert_eq!(
+                                src[2..]
+                                    .iter()
+                                    .map(|byte| char::from(*byte))
+                                    .collect::<Vec<_>>(),
+                                chars
+                            );
+                        }
+                        (Err(expected), Err(error)) => {
+                            assert_eq!(Error::InvalidAttribute(pos, char::from(value)), error);
+                            assert_eq!(expected, error);
+                        }
+                        (expected, result) => {
+                            panic!("{src:?}: expected {expected:?}, got {result:?}");
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cfi_group_trait_positions() {
+        let mut parser = TraitParser::default();
+        visit_all_groups(&mut parser);
+        assert_eq!(group_count(), parser.groups);
+    }
+
+    /// Check that an attribute parses `X` as its undefined value.
+    fn check_undefined<A: AttrInfo>() {
+        let attr = A::from_code_byte(b'X').expect("Undefined attribute was rejected");
+        assert_eq!('X', attr.code_char());
+        assert_eq!("Undefined", attr.label());
+        assert!(A::VARIANTS.iter().any(|variant| variant.code_char() == 'X'));
+    }
+
+    /// Parses every group with all of its attributes undefined.
+    #[derive(Debug, Default)]
+    struct UndefinedParser {
+        /// The number of groups visited.
+        groups: usize,
+    }
+
+    impl GroupVisitor for UndefinedParser {
+        fn visit<G: GroupInfo + Debug>(&mut self, category: u8, group: u8) {
+            self.groups += 1;
+            check_undefined::<G::Attr1>();
+            check_undefined::<G::Attr2>();
+            check_undefined::<G::Attr3>();
+            check_undefined::<G::Attr4>();
+
+            let src = [category, group, b'X', b'X', b'X', b'X'];
+            G::from_cfi_bytes(&src).expect("Could not parse undefined attributes");
+            let code = Code::from_bytes(&src).expect("Could not parse undefined attributes");
+            assert_eq!(src, code.to_bytes());
+        }
+    }
+
+    #[test]
+    fn from_bytes_undefined() {
+        let mut parser = UndefinedParser::default();
+        visit_all_groups(&mut parser);
+        assert_eq!(group_count(), parser.groups);
+    }
 }
 
 macros::impl_attr! {
@@ -0,0 +1,81 @@
+//! Fixed-capacity lists.
+
+use core::{iter::Flatten, slice::Iter};
+
+/// A list of at most `N` values, which silently drops any values pushed beyond its capacity.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub(crate) struct FixedList<T: Copy, const N: usize> {
+    /// The storage for the values pushed.
+    list: [Option<T>; N],
+
+    /// The number of values pushed.
+    len: usize,
+}
+
+impl<T: Copy, const N: usize> FixedList<T, N> {
+    /// Create a new, empty list.
+    pub(crate) const fn new() -> Self {
+        Self {
+            list: [None; N],
+            len: 0,
+        }
+    }
+
+    /// Append a value to the list, if there is room for it.
+    pub(crate) const fn push(&mut self, value: T) {
+        if self.len < N {
+            self.list[self.len] = Some(value);
+            self.len += 1;
+        }
+    }
+
+    /// Whether no values were pushed.
+    pub(crate) const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of values pushed.
+    pub(crate) const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// The first value pushed.
+    pub(crate) const fn first(&self) -> Option<T> {
+        if N == 0 { None } else { self.list[0] }
+    }
+
+    /// Iterate over the values pushed, in order.
+    pub(crate) fn iter(&self) -> Flatten<Iter<'_, Option<T>>> {
+        self.list[..self.len].iter().flatten()
+    }
+}
+
+impl<T: Copy, const N: usize> Default for FixedList<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn push_capacity() {
+        let mut list = FixedList::<usize, 2>::new();
+        assert!(list.is_empty());
+        assert_eq!(None, list.first());
+
+        for value in 0..=2 {
+            list.push(value);
+        }
+
+        assert_eq!(2, list.len());
+        assert_eq!(Some(0), list.first());
+        assert_eq!([&0, &1], *list.iter().collect::<Vec<_>>());
+    }
+}
@@ -3,22 +3,22 @@
 /// Generate a new attribute.
 macro_rules! impl_attr {
     (
-        $(#[$doc:meta])*
+        $(#[doc = $doc:literal])*
         $access:vis enum $name:ident[$($idx:literal),+] {
             $(
-                $(#[$vardoc:meta])*
+                $(#[doc = $vardoc:literal])*
                 $variant:ident = $value:literal, $char:literal;
             )*
         }
     ) => {
         pastey::paste! {
-            $(#[$doc])*
+            $(#[doc = $doc])*
             #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
             #[repr(u8)]
             $access enum $name {
                 $(
                     #[doc = "`" $char "`: "]
-                    $(#[$vardoc])*
+                    $(#[doc = $vardoc])*
                     $variant = $value,
                 )*
 
@@ -27,6 +27,22 @@ macro_rules! impl_attr {
             }
 
             impl $name {
+                /// Every variant of this attribute, including [`Self::Undefined`].
+                $access const ALL: &[Self] = &[$(Self::$variant,)* Self::Undefined];
+
+                /// The CFI characters of every variant of this attribute, in the same order as
+                /// [`Self::ALL`].
+                $access const CHARS: &[u8] = &[$($value,)* b'X'];
+
+                /// The names of every variant of this attribute.
+                $access const NAMES: &[&str] = &[$(stringify!($variant),)* "Undefined"];
+
+                /// The short descriptions of every variant of this attribute.
+                $access const DESCRIPTIONS: &[&str] = &[
+                    $(crate::describe::short_description(concat!($($vardoc, "\n",)*)),)*
+                    "Not applicable/undefined",
+                ];
+
                 $(
                     #[doc = " Check if this value is [`" $name "::" $variant "`]."]
                     #[inline]
@@ -43,6 +59,52 @@ macro_rules! impl_attr {
                         matches!(self, Self::Undefined)
                 }
 
+                /// Encode this attribute as its CFI byte.
+                #[inline]
+                #[must_use]
+                $access const fn to_byte(&self) -> u8 {
+                    *self as u8
+                }
+
+                /// Get the name of this attribute's variant.
+                #[inline]
+                #[must_use]
+                pub(crate) const fn name(&self) -> &'static str {
+                    match self {
+                        $(
+                            Self::$variant => stringify!($variant),
+                        )*
+                        Self::Undefined => "Undefined",
+                    }
+                }
+
+                /// Get the short description of this attribute's variant.
+                #[inline]
+                #[must_use]
+                pub(crate) const fn description(&self) -> &'static str {
+                    match self {
+                        $(
+                            Self::$variant => const {
+                                crate::describe::short_description(concat!($($vardoc, "\n",)*))
+                            },
+                        )*
+                        Self::Undefined => "Not applicable/undefined",
+                    }
+                }
+
+                /// Parse the given variant name into this attribute.
+                #[inline]
+                #[must_use]
+                $access fn from_name(name: &str) -> Option<Self> {
+                    match name {
+                        $(
+                            stringify!($variant) => Some(Self::$variant),
+                        )*
+                        "Undefined" => Some(Self::Undefined),
+                        _ => None,
+                    }
+                }
+
                 /// Parse the given CFI byte into this attribute.
                 ///
                 /// # Errors
@@ -54,6 +116,7 @@ macro_rules! impl_attr {
                         $(
                             $value => Ok(Self::$variant),
                         )*
+                        b'X' => Ok(Self::Undefined),
                         other => Err(crate::error::Error::InvalidAttribute(0, other as char)),
                     }
                 }
@@ -80,6 +143,20 @@ macro_rules! impl_attr {
                 }
             }
 
+            const _: () = {
+                $(
+                    assert!(
+                        $char.len() == 1 && $char.as_bytes()[0] == $value,
+                        concat!(
+                            "documented character does not match ",
+                            stringify!($name),
+                            "::",
+                            stringify!($variant),
+                        ),
+                    );
+                )*
+            };
+
             impl crate::Attr for $name {
                 #[inline]
                 fn from_code_byte(value: u8) -> crate::error::Result<Self> {
@@ -87,8 +164,26 @@ macro_rules! impl_attr {
                 }
             }
 
+            impl crate::AttrInfo for $name {
+                const NAME: &'static str = stringify!($name);
+                const POSITIONS: &'static [usize] = &[$($idx),+];
+                const VARIANTS: &'static [Self] = Self::ALL;
+                const NAMES: &'static [&'static str] = Self::NAMES;
+                const DESCRIPTIONS: &'static [&'static str] = Self::DESCRIPTIONS;
+
+                #[inline]
+                fn code_char(&self) -> char {
+                    self.to_byte() as char
+                }
+            }
+
             $(
-                impl crate::AttrPos<$idx> for $name {}
+                impl crate::AttrPos<$idx> for $name {
+                    #[inline]
+                    fn from_cfi_bytes(value: &[u8]) -> crate::error::Result<Self> {
+                        Self::from_bytes(value, $idx)
+                    }
+                }
             )*
         }
     };
@@ -96,9 +191,15 @@ macro_rules! impl_attr {
 
 /// Generate a new group.
 macro_rules! impl_group {
+    (@validate $group:ident) => {
+        crate::validate::Violations::new()
+    };
+    (@validate $group:ident, $check:ident) => {
+        $group.$check()
+    };
     {
         $(#[$doc:meta])*
-        pub struct $name:ident {
+        pub struct $name:ident $([$check:ident])? {
             $(
                 $(#[$memdoc:meta])*
                 pub $member:ident: $value:ty, $offset:literal;
@@ -116,6 +217,9 @@ macro_rules! impl_group {
         }
 
         impl $name {
+            /// The number of valid attribute combinations in this group.
+            pub const COUNT: usize = 1 $(* <$value>::ALL.len())*;
+
             $(
                 $(#[$memdoc])*
                 #[inline]
@@ -125,6 +229,61 @@ macro_rules! impl_group {
                 }
             )*
 
+            /// Describe the attributes of this group.
+            #[inline]
+            #[must_use]
+            pub const fn describe_attrs(&self) -> [crate::Label; 4] {
+                [
+                    $(
+                        crate::Label {
+                            field: stringify!($member),
+                            name: self.$member().name(),
+                            description: self.$member().description(),
+                        },
+                    )*
+                ]
+            }
+
+            /// Iterate over every valid combination of attributes in this group.
+            #[inline]
+            pub fn iter() -> impl ExactSizeIterator<Item = Self> {
+                (0..Self::COUNT).map(|mut idx| Self {
+                    $(
+                        $member: {
+                            let attr = <$value>::ALL[idx % <$value>::ALL.len()];
+                            idx /= <$value>::ALL.len();
+                            attr
+                        },
+                    )*
+                })
+            }
+
+            /// Encode this group, including its category and group characters, as a CFI code.
+            #[inline]
+            #[must_use]
+            pub const fn to_bytes(&self) -> [u8; crate::CFI_LENGTH] {
+                let mut retval = [Self::CATEGORY, Self::GROUP, 0, 0, 0, 0];
+                $(
+                    retval[$offset + 1] = self.$member().to_byte();
+                )*
+                retval
+            }
+
+            /// Record a diagnostic for every attribute character in the given slice which is
+            /// invalid for this group.
+            pub(crate) const fn diagnose(src: &[u8], diagnostics: &mut crate::Diagnostics) {
+                $(
+                    if src.len() > $offset + 1 && <$value>::from_byte(src[$offset + 1]).is_err() {
+                        diagnostics.push(crate::Diagnostic {
+                            idx: $offset + 1,
+                            value: src[$offset + 1] as char,
+                            field: stringify!($member),
+                            allowed: <$value>::CHARS,
+                        });
+                    }
+                )*
+            }
+
             /// Parse the given byte slice into the attributes for this group.
             ///
             /// # Errors
@@ -137,7 +296,7 @@ macro_rules! impl_group {
             pub const fn from_bytes(src: &[u8]) -> crate::error::Result<Self> {
                 Ok(Self {
                     $(
-                        $member: match <$value>::from_bytes(src, $offset + 2) {
+                        $member: match <$value>::from_bytes(src, $offset + 1) {
                             Ok(value) => value,
                             Err(error) => return Err(error),
                         },
@@ -146,6 +305,84 @@ macro_rules! impl_group {
             }
         }
 
+        #[cfg(feature = "serde")]
+        impl $name {
+            /// The length of the longest field or attribute name in this group.
+            pub(crate) const NAME_LENGTH: usize = {
+                let mut len = 0;
+                $(
+                    len = crate::serde::exploded::longest(len, stringify!($member).len());
+                    len = crate::serde::exploded::longest(
+                        len,
+                        crate::serde::exploded::longest_name(<$value>::NAMES),
+                    );
+                )*
+                len
+            };
+
+            /// Write the attributes of this group as exploded map entries.
+            pub(crate) fn serialize_exploded<M: ::serde::ser::SerializeMap>(
+                &self,
+                map: &mut M,
+            ) -> Result<(), M::Error> {
+                $(
+                    map.serialize_entry(stringify!($member), self.$member().name())?;
+                )*
+                Ok(())
+            }
+
+            /// Read the attributes of this group from exploded map entries.
+            pub(crate) fn deserialize_exploded<E: ::serde::de::Error>(
+                entries: &crate::serde::exploded::Entries,
+                bytes: &mut [u8; crate::CFI_LENGTH],
+            ) -> Result<(), E> {
+                $(
+                    bytes[$offset + 1] = entries
+                        .attr(stringify!($member), <$value>::NAMES, <$value>::from_name)?
+                        .to_byte();
+                )*
+                Ok(())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl crate::serde::exploded::Exploded for $name {
+            #[inline]
+            fn to_cfi(&self) -> [u8; crate::CFI_LENGTH] {
+                self.to_bytes()
+            }
+
+            #[inline]
+            fn from_cfi(value: &[u8; crate::CFI_LENGTH]) -> crate::error::Result<Self> {
+                if value[crate::CATEGORY_IDX] != Self::CATEGORY {
+                    return Err(crate::Error::InvalidCategory(value[crate::CATEGORY_IDX] as char));
+                }
+
+                if value[crate::GROUP_IDX] != Self::GROUP {
+                    return Err(crate::Error::InvalidGroup(value[crate::GROUP_IDX] as char));
+                }
+
+                Self::from_bytes(value)
+            }
+        }
+
+        impl crate::GroupInfo for $name {
+            const FIELDS: [&'static str; 4] = [$(stringify!($member)),*];
+            const POSITIONS: [usize; 4] = [$($offset + 1),*];
+
+            #[inline]
+            fn labels(&self) -> [crate::Label; 4] {
+                self.describe_attrs()
+            }
+        }
+
+        impl crate::validate::Validate for $name {
+            #[inline]
+            fn validate(&self) -> crate::validate::Violations {
+                crate::macros::impl_group!(@validate self $(, $check)?)
+            }
+        }
+
         impl crate::CfiGroup for $name {
             pastey::paste! {
                 $(
@@ -160,18 +397,7 @@ macro_rules! impl_group {
 
             #[inline]
             fn from_cfi_bytes(value: &[u8]) -> crate::error::Result<Self> {
-                if value.len() != crate::CFI_LENGTH {
-                    return Err(crate::error::Error::InvalidLength);
-                }
-
-                Ok(Self {
-                    $(
-                        $member: match <$value as crate::Attr>::from_code_byte(value[$offset + 2]) {
-                            Ok(member) => member,
-                            Err(error) => return Err(error),
-                        },
-                    )*
-                })
+                Self::from_bytes(value)
             }
         }
     };
@@ -179,28 +405,116 @@ macro_rules! impl_group {
 
 /// Generate a new category.
 macro_rules! impl_category {
+    (@name $name:ident) => {
+        stringify!($name)
+    };
+    (@name $name:ident, $label:literal) => {
+        $label
+    };
     (
-        $(#[$doc:meta])*
-        $access:vis enum $name:ident {
+        $(#[doc = $doc:literal])*
+        $access:vis enum $name:ident[$category:literal $(, $label:literal)?] {
             $(
-                $(#[$vardoc:meta])*
+                $(#[doc = $vardoc:literal])*
                 $variant:ident($data:ident) = $value:literal, $char:literal;
             )*
         }
     ) => {
         pastey::paste! {
-            $(#[$doc])*
+            $(#[doc = $doc])*
             #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
             #[repr(u8)]
             $access enum $name {
                 $(
                     #[doc = " `" $char "`: "]
-                    $(#[$vardoc])*
+                    $(#[doc = $vardoc])*
                     $variant($data) = $value,
                 )*
             }
 
+            const _: () = {
+                $(
+                    assert!(
+                        $char.len() == 1 && $char.as_bytes()[0] == $value,
+                        concat!(
+                            "documented character does not match ",
+                            stringify!($name),
+                            "::",
+                            stringify!($variant),
+                        ),
+                    );
+                )*
+            };
+
+            $(
+                impl $data {
+                    /// The category character of this group.
+                    pub const CATEGORY: u8 = $category;
+
+                    /// The group character of this group.
+                    pub const GROUP: u8 = $value;
+
+                    /// The name of this group.
+                    pub const NAME: &str = stringify!($variant);
+
+                    /// The short description of this group.
+                    pub const DESCRIPTION: &str =
+                        crate::describe::title(concat!($($vardoc, "\n",)*));
+                }
+            )*
+
             impl $name {
+                /// The category character of this category.
+                pub const CATEGORY: u8 = $category;
+
+                /// The number of valid codes in this category.
+                pub const COUNT: usize = 0 $(+ <$data>::COUNT)*;
+
+                /// The group characters of every group in this category.
+                pub const GROUPS: &[u8] = &[$($value),*];
+
+                /// The name of this category, matching its [`Code`](crate::Code) variant.
+                pub const NAME: &str = crate::macros::impl_category!(@name $name $(, $label)?);
+
+                /// The short description of this category.
+                pub const DESCRIPTION: &str =
+                    crate::describe::title(concat!($($doc, "\n",)*));
+
+                /// Describe this category, its group, and attributes.
+                #[inline]
+                #[must_use]
+                $access const fn describe(&self) -> crate::Description {
+                    let (group, attributes) = match self {
+                        $(
+                            Self::$variant(group) => (
+                                crate::Label {
+                                    field: "group",
+                                    name: <$data>::NAME,
+                                    description: <$data>::DESCRIPTION,
+                                },
+                                group.describe_attrs(),
+                            ),
+                        )*
+                    };
+
+                    crate::Description {
+                        category: crate::Label {
+                            field: "category",
+                            name: Self::NAME,
+                            description: Self::DESCRIPTION,
+                        },
+                        group,
+                        attributes,
+                    }
+                }
+
+                /// Iterate over every valid code in this category.
+                #[inline]
+                $access fn iter() -> impl Iterator<Item = Self> {
+                    core::iter::empty()
+                        $(.chain(<$data>::iter().map(Self::$variant)))*
+                }
+
                 $(
                     #[doc = "Whether the group value is [`Self::" $variant "`]."]
                     #[inline]
@@ -210,6 +524,17 @@ macro_rules! impl_category {
                     }
                 )*
 
+                /// Encode this category data as a CFI code.
+                #[inline]
+                #[must_use]
+                $access const fn to_bytes(&self) -> [u8; crate::CFI_LENGTH] {
+                    match self {
+                        $(
+                            Self::$variant(group) => group.to_bytes(),
+                        )*
+                    }
+                }
+
                 /// Parse the given byte string into this category data.
                 ///
                 /// # Errors
@@ -223,6 +548,12 @@ macro_rules! impl_category {
                         return Err(crate::Error::InvalidLength);
                     }
 
+                    if value[crate::CATEGORY_IDX] != Self::CATEGORY {
+                        return Err(crate::Error::InvalidCategory(
+                            value[crate::CATEGORY_IDX] as char,
+                        ));
+                    }
+
                     match value[crate::GROUP_IDX] {
                         $(
                             $value => match <$data>::from_bytes(value) {
@@ -235,6 +566,158 @@ macro_rules! impl_category {
                     }
                 }
             }
+
+            impl $name {
+                /// Record a diagnostic for every group or attribute character in the given slice
+                /// which is invalid for this category.
+                pub(crate) const fn diagnose(src: &[u8], diagnostics: &mut crate::Diagnostics) {
+                    if src.len() <= crate::GROUP_IDX {
+                        return;
+                    }
+
+                    match src[crate::GROUP_IDX] {
+                        $(
+                            $value => <$data>::diagnose(src, diagnostics),
+                        )*
+                        other => diagnostics.push(crate::Diagnostic {
+                            idx: crate::GROUP_IDX,
+                            value: other as char,
+                            field: "group",
+                            allowed: Self::GROUPS,
+                        }),
+                    }
+                }
+            }
+
+            impl crate::validate::Validate for $name {
+                #[inline]
+                fn validate(&self) -> crate::validate::Violations {
+                    match self {
+                        $(
+                            Self::$variant(group) => crate::validate::Validate::validate(group),
+                        )*
+                    }
+                }
+            }
+
+            #[cfg(test)]
+            impl $name {
+                /// Visit the type of every group in this category.
+                pub(crate) fn visit_groups<V: crate::test::GroupVisitor>(visitor: &mut V) {
+                    $(
+                        visitor.visit::<$data>(Self::CATEGORY, <$data>::GROUP);
+                    )*
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl $name {
+                /// The names of every group in this category.
+                const GROUP_NAMES: &[&str] = &[$(stringify!($variant)),*];
+
+                /// The length of the longest name of this category, its groups, and their fields
+                /// and attributes.
+                pub(crate) const NAME_LENGTH: usize = {
+                    let mut len = crate::serde::exploded::longest(
+                        Self::NAME.len(),
+                        crate::serde::exploded::longest_name(Self::GROUP_NAMES),
+                    );
+                    $(
+                        len = crate::serde::exploded::longest(len, <$data>::NAME_LENGTH);
+                    )*
+                    len
+                };
+
+                /// Write the category, group, and attributes of this category as exploded map
+                /// entries.
+                pub(crate) fn serialize_exploded<M: ::serde::ser::SerializeMap>(
+                    &self,
+                    map: &mut M,
+                ) -> Result<(), M::Error> {
+                    map.serialize_entry("category", Self::NAME)?;
+                    match self {
+                        $(
+                            Self::$variant(group) => {
+                                map.serialize_entry("group", stringify!($variant))?;
+                                group.serialize_exploded(map)
+                            }
+                        )*
+                    }
+                }
+
+                /// Read the group and attributes of this category from exploded map entries.
+                pub(crate) fn deserialize_exploded<E: ::serde::de::Error>(
+                    entries: &crate::serde::exploded::Entries,
+                    bytes: &mut [u8; crate::CFI_LENGTH],
+                ) -> Result<(), E> {
+                    bytes[crate::CATEGORY_IDX] = Self::CATEGORY;
+                    match entries.name("group")? {
+                        $(
+                            stringify!($variant) => {
+                                bytes[crate::GROUP_IDX] = <$data>::GROUP;
+                                <$data>::deserialize_exploded(entries, bytes)
+                            }
+                        )*
+                        other => Err(E::unknown_variant(other, Self::GROUP_NAMES)),
+                    }
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl crate::serde::exploded::Exploded for $name {
+                #[inline]
+                fn to_cfi(&self) -> [u8; crate::CFI_LENGTH] {
+                    self.to_bytes()
+                }
+
+                #[inline]
+                fn from_cfi(value: &[u8; crate::CFI_LENGTH]) -> crate::error::Result<Self> {
+                    Self::from_bytes(value)
+                }
+            }
+
+            impl core::fmt::Display for $name {
+                #[inline]
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    crate::fmt_bytes(self.to_bytes(), f)
+                }
+            }
+
+            impl core::str::FromStr for $name {
+                type Err = crate::Error;
+
+                #[inline]
+                fn from_str(value: &str) -> crate::Result<Self> {
+                    Self::from_bytes(value.as_bytes())
+                }
+            }
+
+            impl TryFrom<&str> for $name {
+                type Error = crate::Error;
+
+                #[inline]
+                fn try_from(value: &str) -> crate::Result<Self> {
+                    Self::from_bytes(value.as_bytes())
+                }
+            }
+
+            impl TryFrom<&[u8]> for $name {
+                type Error = crate::Error;
+
+                #[inline]
+                fn try_from(value: &[u8]) -> crate::Result<Self> {
+                    Self::from_bytes(value)
+                }
+            }
+
+            impl TryFrom<[u8; crate::CFI_LENGTH]> for $name {
+                type Error = crate::Error;
+
+                #[inline]
+                fn try_from(value: [u8; crate::CFI_LENGTH]) -> crate::Result<Self> {
+                    Self::from_bytes(&value)
+                }
+            }
         }
     };
 }
@@ -0,0 +1,162 @@
+//! Miscellaneous category support.
+
+use crate::{Form, NotApplicable, equities::Ownership, macros};
+
+macros::impl_category! {
+    /// Miscellaneous/other instruments.
+    ///
+    /// Financial instruments that do not fit the above categories as defined.
+    pub enum Misc[b'M'] {
+        /// Combined instruments.
+        ///
+        /// Units consisting of two or more different financial instruments which are traded
+        /// together as a single unit.
+        Combined(Combined) = b'C', "C";
+
+        /// Other assets (miscellaneous).
+        ///
+        /// Assets which do not fit into any of the other categories or groups.
+        OtherAsset(OtherAsset) = b'M', "M";
+    }
+}
+
+macros::impl_group! {
+    /// Combined instruments.
+    ///
+    /// Units consisting of two or more different financial instruments which are traded together
+    /// as a single unit.
+    pub struct Combined {
+        /// Component (indicates the types of instruments which make up the combined unit).
+        pub component: Component, 1;
+
+        /// Ownership/transfer/sales restrictions (the ownership or transfer of the security is
+        /// subject to special conditions including country-specific restrictions).
+        pub ownership: Ownership, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Form (negotiability, transmission).
+        pub form: Form, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Other assets (miscellaneous).
+    ///
+    /// Assets which do not fit into any of the other categories or groups.
+    pub struct OtherAsset {
+        /// Further grouping (indicates the type of asset).
+        pub kind: AssetKind, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Not applicable/undefined.
+        pub unassigned4: NotApplicable, 4;
+    }
+}
+
+macros::impl_attr! {
+    /// Component.
+    ///
+    /// Indicates the types of instruments which make up the combined unit.
+    pub enum Component[2] {
+        /// Combination of shares (with different characteristics).
+        Shares = b'S', "S";
+
+        /// Combination of bonds (with different characteristics).
+        Bonds = b'B', "B";
+
+        /// Share and bond.
+        ShareAndBond = b'H', "H";
+
+        /// Share and warrant.
+        ShareAndWarrant = b'A', "A";
+
+        /// Warrant and warrant (with different characteristics).
+        Warrants = b'W', "W";
+
+        /// Fund units and other components.
+        FundUnits = b'U', "U";
+
+        /// Others (miscellaneous).
+        Other = b'M', "M";
+    }
+}
+
+macros::impl_attr! {
+    /// Further grouping.
+    ///
+    /// Indicates the type of asset.
+    pub enum AssetKind[2] {
+        /// Real estate deeds.
+        RealEstate = b'R', "R";
+
+        /// Insurance policies.
+        Insurance = b'I', "I";
+
+        /// Escrow receipts.
+        Escrow = b'E', "E";
+
+        /// Trade finance instruments.
+        TradeFinance = b'T', "T";
+
+        /// Carbon credit.
+        CarbonCredit = b'N', "N";
+
+        /// Precious metal receipts.
+        PreciousMetal = b'P', "P";
+
+        /// Other OTC derivative products.
+        OtcDerivative = b'S', "S";
+
+        /// Others (miscellaneous).
+        Other = b'M', "M";
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CFI_LENGTH, Code};
+
+    #[yare::parameterized(
+        combined = {
+            *b"MCAUXR",
+            Misc::Combined(Combined {
+                component: Component::ShareAndWarrant,
+                ownership: Ownership::Free,
+                unassigned3: NotApplicable::Undefined,
+                form: Form::Registered,
+            })
+        },
+        combined_undefined = {
+            *b"MCXXXX",
+            Misc::Combined(Combined {
+                component: Component::Undefined,
+                ownership: Ownership::Undefined,
+                unassigned3: NotApplicable::Undefined,
+                form: Form::Undefined,
+            })
+        },
+        other_asset = {
+            *b"MMNXXX",
+            Misc::OtherAsset(OtherAsset {
+                kind: AssetKind::CarbonCredit,
+                unassigned2: NotApplicable::Undefined,
+                unassigned3: NotApplicable::Undefined,
+                unassigned4: NotApplicable::Undefined,
+            })
+        },
+    )]
+    fn from_bytes(src: [u8; CFI_LENGTH], expected: Misc) {
+        let misc = Misc::from_bytes(&src).expect("Could not parse code");
+        assert_eq!(expected, misc);
+        assert_eq!(src, misc.to_bytes());
+        assert_eq!(Ok(Code::Misc(expected)), Code::from_bytes(&src));
+    }
+}
@@ -9,7 +9,7 @@ macros::impl_category! {
     /// either the privilege to purchase or the privilege to sell the assets specified at a
     /// predetermined price or formula at or within a time in the future. Where a listed option
     /// cannot be classified within this Category, refer to non-listed and complex listed options.
-    pub enum Listed {
+    pub enum Listed[b'O', "ListedOption"] {
         /// Call options.
         ///
         /// Contracts between a buyer and a seller giving the buyer (holder) the right, but not the
@@ -38,7 +38,7 @@ macros::impl_category! {
     /// not captured by the listed options Category (see "Others"). An option grants the holder
     /// either the privilege to purchase or the privilege to sell the assets specified at a
     /// predetermined price or formula at or within a time in the future.
-    pub enum Unlisted {
+    pub enum Unlisted[b'H', "UnlistedOption"] {
         /// Rates.
         ///
         /// An option where the holder of the option has the right but not the obligation to enter
@@ -843,3 +843,34 @@ macros::impl_attr! {
         Auction = b'A', "A";
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::CFI_LENGTH;
+
+    #[yare::parameterized(
+        call = {*b"OCXXXX", Listed::is_call},
+        put = {*b"OPXXXX", Listed::is_put},
+        other = {*b"OMXXXX", Listed::is_other},
+    )]
+    fn from_bytes_undefined_listed(src: [u8; CFI_LENGTH], func: fn(&Listed) -> bool) {
+        let listed = Listed::from_bytes(&src).expect("Could not parse undefined attributes");
+        assert!(func(&listed));
+        assert_eq!(src, listed.to_bytes());
+    }
+
+    #[yare::parameterized(
+        rate = {*b"HRXXXX", Unlisted::is_rate},
+        commodity = {*b"HTXXXX", Unlisted::is_commodity},
+        equity = {*b"HEXXXX", Unlisted::is_equity},
+        credit = {*b"HCXXXX", Unlisted::is_credit},
+        forex = {*b"HFXXXX", Unlisted::is_forex},
+        other = {*b"HMXXXX", Unlisted::is_other},
+    )]
+    fn from_bytes_undefined_unlisted(src: [u8; CFI_LENGTH], func: fn(&Unlisted) -> bool) {
+        let unlisted = Unlisted::from_bytes(&src).expect("Could not parse undefined attributes");
+        assert!(func(&unlisted));
+        assert_eq!(src, unlisted.to_bytes());
+    }
+}
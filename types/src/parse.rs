@@ -0,0 +1,298 @@
+//! Lenient parsing of codes with normalization.
+
+use crate::{CATEGORY_IDX, CFI_LENGTH, Code, Error, GROUP_IDX, Result};
+
+/// A normalization which was applied to input before it could be parsed.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum Warning {
+    /// Leading or trailing whitespace was removed.
+    Trimmed = 1 << 0,
+
+    /// Lowercase letters were converted to uppercase.
+    Lowercase = 1 << 1,
+
+    /// A `-` or `_` placeholder was replaced with `X`.
+    Placeholder = 1 << 2,
+
+    /// A five-character code was padded with a trailing `X`.
+    Padded = 1 << 3,
+}
+
+impl Warning {
+    /// Every warning, in the order normalizations are applied.
+    pub const ALL: &[Self] = &[
+        Self::Trimmed,
+        Self::Lowercase,
+        Self::Placeholder,
+        Self::Padded,
+    ];
+}
+
+/// A set of the normalizations applied to input.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Warnings(u8);
+
+impl Warnings {
+    /// Create a new, empty set of warnings.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Add a warning to this set.
+    const fn insert(&mut self, warning: Warning) {
+        self.0 |= warning as u8;
+    }
+
+    /// Whether the given warning is in this set.
+    #[must_use]
+    #[inline]
+    pub const fn contains(&self, warning: Warning) -> bool {
+        self.0 & warning as u8 != 0
+    }
+
+    /// Whether no normalizations were applied.
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Iterate over the warnings in this set, in the order normalizations are applied.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = Warning> {
+        let warnings = *self;
+        Warning::ALL
+            .iter()
+            .copied()
+            .filter(move |warning| warnings.contains(*warning))
+    }
+}
+
+/// A code parsed with normalization, and the normalizations which were applied.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Normalized {
+    /// The parsed code.
+    pub code: Code,
+
+    /// The normalizations applied to the input.
+    pub warnings: Warnings,
+}
+
+/// The normalizations to allow when parsing a code.
+///
+/// Normalization never guesses: whitespace or placeholders inside a code, codes of any other
+/// length, and normalized codes which are not valid are all rejected with the error strict
+/// parsing would report at that position, naming the character as it was written.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso10962_types::{Code, ParseOptions, Warning};
+///
+/// let normalized = ParseOptions::LENIENT
+///     .parse(b" es-uf ")
+///     .expect("Could not parse code");
+///
+/// assert_eq!(Code::from_bytes(b"ESXUFX"), Ok(normalized.code));
+/// assert!(normalized.warnings.contains(Warning::Padded));
+///
+/// assert!(ParseOptions::STRICT.parse(b" es-uf ").is_err());
+/// assert!(ParseOptions::LENIENT.parse(b"ES VUFR").is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ParseOptions {
+    /// The normalizations which may be applied.
+    allowed: Warnings,
+}
+
+impl ParseOptions {
+    /// Options which allow no normalization, equivalent to [`Code::from_bytes()`].
+    pub const STRICT: Self = Self::new();
+
+    /// Options which allow every normalization.
+    pub const LENIENT: Self = Self::new()
+        .trim(true)
+        .uppercase(true)
+        .placeholders(true)
+        .pad(true);
+
+    /// Create a new set of options which allow no normalization.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            allowed: Warnings::new(),
+        }
+    }
+
+    /// Set whether the given normalization may be applied.
+    const fn allow(mut self, warning: Warning, allow: bool) -> Self {
+        if allow {
+            self.allowed.0 |= warning as u8;
+        } else {
+            self.allowed.0 &= !(warning as u8);
+        }
+        self
+    }
+
+    /// Set whether to remove leading and trailing ASCII whitespace.
+    #[must_use]
+    #[inline]
+    pub const fn trim(self, trim: bool) -> Self {
+        self.allow(Warning::Trimmed, trim)
+    }
+
+    /// Set whether to convert lowercase letters to uppercase.
+    #[must_use]
+    #[inline]
+    pub const fn uppercase(self, uppercase: bool) -> Self {
+        self.allow(Warning::Lowercase, uppercase)
+    }
+
+    /// Set whether to replace `-` and `_` placeholders with `X`.
+    #[must_use]
+    #[inline]
+    pub const fn placeholders(self, placeholders: bool) -> Self {
+        self.allow(Warning::Placeholder, placeholders)
+    }
+
+    /// Set whether to pad five-character codes with a trailing `X`.
+    #[must_use]
+    #[inline]
+    pub const fn pad(self, pad: bool) -> Self {
+        self.allow(Warning::Padded, pad)
+    }
+
+    /// Normalize and parse the given byte slice into a code.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidLength`] if the normalized input is not [`CFI_LENGTH`] bytes.
+    /// - A more specific error if the normalized input is not a valid code.
+    #[inline]
+    pub const fn parse(&self, src: &[u8]) -> Result<Normalized> {
+        let mut warnings = Warnings::new();
+
+        let mut src = src;
+        if self.allowed.contains(Warning::Trimmed) {
+            let trimmed = src.trim_ascii();
+            if trimmed.len() != src.len() {
+                warnings.insert(Warning::Trimmed);
+                src = trimmed;
+            }
+        }
+
+        let mut bytes = [b'X'; CFI_LENGTH];
+        if src.len() == CFI_LENGTH - 1 && self.allowed.contains(Warning::Padded) {
+            warnings.insert(Warning::Padded);
+        } else if src.len() != CFI_LENGTH {
+            return Err(Error::InvalidLength);
+        }
+
+        let mut idx = 0;
+        while idx < src.len() {
+            bytes[idx] = match src[idx] {
+                value @ b'a'..=b'z' if self.allowed.contains(Warning::Lowercase) => {
+                    warnings.insert(Warning::Lowercase);
+                    value.to_ascii_uppercase()
+                }
+                b'-' | b'_' if self.allowed.contains(Warning::Placeholder) => {
+                    warnings.insert(Warning::Placeholder);
+                    b'X'
+                }
+                value => value,
+            };
+            idx += 1;
+        }
+
+        match Code::from_bytes(&bytes) {
+            Ok(code) => Ok(Normalized { code, warnings }),
+            Err(error) => Err(as_written(error, src)),
+        }
+    }
+}
+
+/// Report the given error against the character as it was written in the input, rather than its
+/// normalized form.
+const fn as_written(error: Error, src: &[u8]) -> Error {
+    match error {
+        Error::InvalidCategory(_) => Error::InvalidCategory(src[CATEGORY_IDX] as char),
+        Error::InvalidGroup(_) => Error::InvalidGroup(src[GROUP_IDX] as char),
+        Error::InvalidAttribute(idx, _) if idx < src.len() => {
+            Error::InvalidAttribute(idx, src[idx] as char)
+        }
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[yare::parameterized(
+        exact = {b"ESVUFR", *b"ESVUFR", &[]},
+        trimmed = {b"  ESVUFR\t", *b"ESVUFR", &[Warning::Trimmed]},
+        lowercase = {b"esVuFr", *b"ESVUFR", &[Warning::Lowercase]},
+        dash = {b"ES-UFR", *b"ESXUFR", &[Warning::Placeholder]},
+        underscore = {b"ES__FR", *b"ESXXFR", &[Warning::Placeholder]},
+        padded = {b"ESVUF", *b"ESVUFX", &[Warning::Padded]},
+        everything = {b" es_uf\n", *b"ESXUFX", Warning::ALL},
+    )]
+    fn lenient(src: &[u8], expected: [u8; CFI_LENGTH], warnings: &[Warning]) {
+        let normalized = Code::parse_lenient(src).expect("Could not parse code");
+        assert_eq!(expected, normalized.code.to_bytes());
+        assert!(normalized.warnings.iter().eq(warnings.iter().copied()));
+        assert_eq!(warnings.is_empty(), normalized.warnings.is_empty());
+    }
+
+    #[yare::parameterized(
+        inner_space = {b"ES VUFR", Error::InvalidLength},
+        inner_space_attribute = {b"ES UFR", Error::InvalidAttribute(2, ' ')},
+        inner_space_padded = {b"ES UF", Error::InvalidAttribute(2, ' ')},
+        too_short = {b"ESVU", Error::InvalidLength},
+        too_long = {b"ESVUFRX", Error::InvalidLength},
+        padded_invalid = {b"ESVUZ", Error::InvalidAttribute(4, 'Z')},
+        placeholder_category = {b"-SVUFR", Error::InvalidCategory('-')},
+        placeholder_group = {b"E_VUFR", Error::InvalidGroup('_')},
+        lowercase_category = {b"zsvufr", Error::InvalidCategory('z')},
+        lowercase_attribute = {b" eszufr", Error::InvalidAttribute(2, 'z')},
+        other_placeholder = {b"ES.UFR", Error::InvalidAttribute(2, '.')},
+        non_ascii = {"ESVUFÄ".as_bytes(), Error::InvalidLength},
+    )]
+    fn lenient_error(src: &[u8], expected: Error) {
+        assert_eq!(Err(expected), Code::parse_lenient(src));
+    }
+
+    #[yare::parameterized(
+        trimmed = {b" ESVUFR", ParseOptions::STRICT.trim(true), Ok(*b"ESVUFR")},
+        untrimmed = {b" ESVUFR", ParseOptions::LENIENT.trim(false), Err(Error::InvalidLength)},
+        lowercase = {b"esvufr", ParseOptions::STRICT.uppercase(true), Ok(*b"ESVUFR")},
+        not_lowercase = {b"esvufr", ParseOptions::LENIENT.uppercase(false), Err(Error::InvalidCategory('e'))},
+        placeholder = {b"ES-UFR", ParseOptions::STRICT.placeholders(true), Ok(*b"ESXUFR")},
+        no_placeholder = {b"ES-UFR", ParseOptions::LENIENT.placeholders(false), Err(Error::InvalidAttribute(2, '-'))},
+        padded = {b"ESVUF", ParseOptions::STRICT.pad(true), Ok(*b"ESVUFX")},
+        not_padded = {b"ESVUF", ParseOptions::LENIENT.pad(false), Err(Error::InvalidLength)},
+        inner_space_padded = {b"ES UF", ParseOptions::STRICT.pad(true), Err(Error::InvalidAttribute(2, ' '))},
+        inner_space_not_padded = {b"ES UF", ParseOptions::LENIENT.pad(false), Err(Error::InvalidLength)},
+    )]
+    fn options(src: &[u8], options: ParseOptions, expected: Result<[u8; CFI_LENGTH]>) {
+        let result = options
+            .parse(src)
+            .map(|normalized| normalized.code.to_bytes());
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn strict_matches_from_bytes() {
+        for code in Code::iter_all().step_by(97) {
+            let normalized = ParseOptions::STRICT
+                .parse(&code.to_bytes())
+                .expect("Could not parse code");
+            assert_eq!(code, normalized.code);
+            assert!(normalized.warnings.is_empty());
+        }
+    }
+}
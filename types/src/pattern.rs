@@ -0,0 +1,393 @@
+//! CFI code patterns.
+
+use crate::{
+    CATEGORY_IDX, CFI_LENGTH, Code, GROUP_IDX, Result, civ, debt, equities, financing, forwards,
+    futures, misc, options, referential, rights, spot, strategies, swaps,
+};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult, Write as _},
+    str::FromStr,
+};
+use thiserror::Error as ThisError;
+
+/// An enumeration of errors which parsing a [`CfiPattern`] may generate.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, ThisError)]
+pub enum PatternError {
+    /// There are not enough or too many positions for a CFI code.
+    #[error("Invalid length")]
+    InvalidLength,
+
+    /// The pattern character at the given position is invalid.
+    #[error("Invalid pattern character '{1}' at position '{0}'.")]
+    InvalidCharacter(usize, char),
+}
+
+/// The number of characters which may appear in a CFI code position.
+const ALPHABET_LENGTH: u8 = 26;
+
+/// A mask matching any character.
+const ANY: u32 = (1 << ALPHABET_LENGTH) - 1;
+
+/// The groups of every category, as a bit mask from `A` to `Z`, indexed by category.
+const GROUPS: [u32; ALPHABET_LENGTH as usize] = {
+    let categories: [(u8, &[u8]); 14] = [
+        (equities::Equity::CATEGORY, equities::Equity::GROUPS),
+        (debt::Debt::CATEGORY, debt::Debt::GROUPS),
+        (civ::Civ::CATEGORY, civ::Civ::GROUPS),
+        (rights::Right::CATEGORY, rights::Right::GROUPS),
+        (options::Listed::CATEGORY, options::Listed::GROUPS),
+        (futures::Future::CATEGORY, futures::Future::GROUPS),
+        (swaps::Swap::CATEGORY, swaps::Swap::GROUPS),
+        (options::Unlisted::CATEGORY, options::Unlisted::GROUPS),
+        (spot::Spot::CATEGORY, spot::Spot::GROUPS),
+        (forwards::Forward::CATEGORY, forwards::Forward::GROUPS),
+        (strategies::Strategy::CATEGORY, strategies::Strategy::GROUPS),
+        (financing::Financing::CATEGORY, financing::Financing::GROUPS),
+        (
+            referential::Referential::CATEGORY,
+            referential::Referential::GROUPS,
+        ),
+        (misc::Misc::CATEGORY, misc::Misc::GROUPS),
+    ];
+
+    let mut retval = [0u32; ALPHABET_LENGTH as usize];
+    let mut idx = 0;
+    while idx < categories.len() {
+        let (category, groups) = categories[idx];
+        let mut group = 0;
+        while group < groups.len() {
+            retval[(category - b'A') as usize] |= bit(groups[group]);
+            group += 1;
+        }
+        idx += 1;
+    }
+    retval
+};
+
+/// Get the mask bit for the given character, or zero if it cannot appear in a CFI code.
+const fn bit(value: u8) -> u32 {
+    if value.is_ascii_uppercase() {
+        1 << (value - b'A')
+    } else {
+        0
+    }
+}
+
+/// A pattern which matches a set of CFI codes.
+///
+/// Each of the six positions of a pattern is one of:
+///
+/// - A single character, which must match exactly.
+/// - A `*` or `?` wildcard, which matches any character.
+/// - A bracketed set of characters, such as `[CP]`, which matches any character in the set.
+///
+/// Every fixed character must be legal for its position, given the categories and groups the
+/// pattern can match.
+///
+/// # Examples
+///
+/// ```rust
+/// use iso10962_types::{CfiPattern, Code};
+///
+/// let pattern = CfiPattern::new("O[CP]*C**").expect("Invalid pattern");
+/// let code = Code::from_bytes(b"OCASPS").expect("Invalid code");
+///
+/// assert!(!pattern.matches(&code));
+/// assert!(pattern.matches_bytes(b"OPECPS"));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CfiPattern {
+    /// The characters accepted at each position, as a bit mask from `A` to `Z`.
+    masks: [u32; CFI_LENGTH],
+}
+
+impl CfiPattern {
+    /// Parse the given string into a pattern.
+    ///
+    /// # Errors
+    ///
+    /// - [`PatternError::InvalidLength`] if the pattern does not have [`CFI_LENGTH`] positions.
+    /// - [`PatternError::InvalidCharacter`] if a position is malformed or contains a character which is
+    ///   not legal there.
+    #[inline]
+    pub const fn new(pattern: &str) -> Result<Self, PatternError> {
+        Self::from_bytes(pattern.as_bytes())
+    }
+
+    /// Parse the given byte string into a pattern.
+    ///
+    /// # Errors
+    ///
+    /// - [`PatternError::InvalidLength`] if the pattern does not have [`CFI_LENGTH`] positions.
+    /// - [`PatternError::InvalidCharacter`] if a position is malformed or contains a character which is
+    ///   not legal there.
+    #[inline]
+    pub const fn from_bytes(src: &[u8]) -> Result<Self, PatternError> {
+        let mut masks = [0u32; CFI_LENGTH];
+        let mut pos = 0;
+        let mut idx = 0;
+
+        while idx < src.len() {
+            if pos == CFI_LENGTH {
+                return Err(PatternError::InvalidLength);
+            }
+
+            match src[idx] {
+                b'*' | b'?' => masks[pos] = ANY,
+                b'[' => {
+                    idx += 1;
+                    while idx < src.len() && src[idx] != b']' {
+                        let mask = bit(src[idx]);
+                        if mask == 0 {
+                            return Err(PatternError::InvalidCharacter(pos, src[idx] as char));
+                        }
+                        masks[pos] |= mask;
+                        idx += 1;
+                    }
+
+                    if idx == src.len() {
+                        return Err(PatternError::InvalidCharacter(pos, '['));
+                    }
+
+                    if masks[pos] == 0 {
+                        return Err(PatternError::InvalidCharacter(pos, ']'));
+                    }
+                }
+                value => {
+                    masks[pos] = bit(value);
+                    if masks[pos] == 0 {
+                        return Err(PatternError::InvalidCharacter(pos, value as char));
+                    }
+                }
+            }
+
+            idx += 1;
+            pos += 1;
+        }
+
+        if pos != CFI_LENGTH {
+            return Err(PatternError::InvalidLength);
+        }
+
+        match Self::validate(&masks) {
+            Ok(()) => Ok(Self { masks }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Check every fixed character in the given masks is legal for its position.
+    const fn validate(masks: &[u32; CFI_LENGTH]) -> Result<(), PatternError> {
+        let mut pos = 0;
+        while pos < CFI_LENGTH {
+            if masks[pos] != ANY {
+                let mut value = 0;
+                while value < ALPHABET_LENGTH {
+                    if masks[pos] & (1 << value) != 0 && !Self::accepts(masks, pos, b'A' + value) {
+                        return Err(PatternError::InvalidCharacter(pos, (b'A' + value) as char));
+                    }
+                    value += 1;
+                }
+            }
+            pos += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Whether any category and group matched by the masks accepts the value at the given
+    /// position.
+    const fn accepts(masks: &[u32; CFI_LENGTH], pos: usize, value: u8) -> bool {
+        if pos == CATEGORY_IDX {
+            return GROUPS[(value - b'A') as usize] != 0;
+        }
+
+        let mut category = 0;
+        while category < ALPHABET_LENGTH {
+            if masks[CATEGORY_IDX] & (1 << category) != 0 {
+                if pos == GROUP_IDX {
+                    if GROUPS[category as usize] & bit(value) != 0 {
+                        return true;
+                    }
+                } else {
+                    let mut group = 0;
+                    while group < ALPHABET_LENGTH {
+                        if GROUPS[category as usize] & masks[GROUP_IDX] & (1 << group) != 0 {
+                            let mut src = [b'A' + category, b'A' + group, b'X', b'X', b'X', b'X'];
+                            src[pos] = value;
+                            if Code::from_bytes(&src).is_ok() {
+                                return true;
+                            }
+                        }
+                        group += 1;
+                    }
+                }
+            }
+            category += 1;
+        }
+
+        false
+    }
+
+    /// Whether the given code matches this pattern.
+    #[inline]
+    #[must_use]
+    pub const fn matches(&self, code: &Code) -> bool {
+        let bytes = code.to_bytes();
+        let mut pos = 0;
+        while pos < CFI_LENGTH {
+            if self.masks[pos] & bit(bytes[pos]) == 0 {
+                return false;
+            }
+            pos += 1;
+        }
+
+        true
+    }
+
+    /// Whether the given byte string is a valid CFI code which matches this pattern.
+    #[inline]
+    #[must_use]
+    pub const fn matches_bytes(&self, src: &[u8]) -> bool {
+        match Code::from_bytes(src) {
+            Ok(code) => self.matches(&code),
+            Err(_) => false,
+        }
+    }
+}
+
+impl Display for CfiPattern {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for mask in self.masks {
+            if mask == ANY {
+                f.write_char('*')?;
+                continue;
+            }
+
+            let multiple = mask.count_ones() > 1;
+            if multiple {
+                f.write_char('[')?;
+            }
+
+            for value in 0..ALPHABET_LENGTH {
+                if mask & (1 << value) != 0 {
+                    f.write_char((b'A' + value) as char)?;
+                }
+            }
+
+            if multiple {
+                f.write_char(']')?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for CfiPattern {
+    type Err = PatternError;
+
+    #[inline]
+    fn from_str(value: &str) -> Result<Self, PatternError> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<&str> for CfiPattern {
+    type Error = PatternError;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, PatternError> {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn group_table() {
+        for category in b'A'..=b'Z' {
+            for group in b'A'..=b'Z' {
+                let src = [category, group, b'X', b'X', b'X', b'X'];
+                assert_eq!(
+                    Code::from_bytes(&src).is_ok(),
+                    GROUPS[(category - b'A') as usize] & bit(group) != 0,
+                    "{}{}",
+                    char::from(category),
+                    char::from(group)
+                );
+            }
+        }
+    }
+
+    #[yare::parameterized(
+        common_equity = {"ES****", "ESVUFR", true},
+        common_equity_other = {"ES****", "EPNRFR", false},
+        fixed_rate_debt = {"D*F***", "DBFUFR", true},
+        fixed_rate_debt_variable = {"D*F***", "DBVUFR", false},
+        fixed_rate_debt_zero = {"D*F***", "DBZUFR", false},
+        listed_currency = {"O[CP]*C**", "OPECPS", true},
+        listed_stock = {"O[CP]*C**", "OCASPS", false},
+        question = {"E?????", "ESVUFR", true},
+        any = {"******", "IFXXXP", true},
+        invalid_code = {"******", "ESVUFZ", false},
+        short_code = {"******", "ESVUF", false},
+    )]
+    fn matches_bytes(pattern: &str, src: &str, expected: bool) {
+        let pattern = CfiPattern::new(pattern).expect("Could not parse pattern");
+        assert_eq!(expected, pattern.matches_bytes(src.as_bytes()));
+
+        if let Ok(code) = Code::from_bytes(src.as_bytes()) {
+            assert_eq!(expected, pattern.matches(&code));
+        }
+    }
+
+    #[yare::parameterized(
+        short = {"ES***", PatternError::InvalidLength},
+        long = {"ES*****", PatternError::InvalidLength},
+        bad_char = {"ES*!**", PatternError::InvalidCharacter(3, '!')},
+        lowercase = {"es****", PatternError::InvalidCharacter(0, 'e')},
+        unterminated = {"ES*[CP", PatternError::InvalidCharacter(3, '[')},
+        empty_set = {"ES*[]**", PatternError::InvalidCharacter(3, ']')},
+        category = {"Z*****", PatternError::InvalidCharacter(0, 'Z')},
+        category_set = {"[EZ]*****", PatternError::InvalidCharacter(0, 'Z')},
+        group = {"EZ****", PatternError::InvalidCharacter(1, 'Z')},
+        group_in_category = {"[ED]H****", PatternError::InvalidCharacter(1, 'H')},
+        attribute = {"ES**Z*", PatternError::InvalidCharacter(4, 'Z')},
+        attribute_in_group = {"ES*[TR]**", PatternError::InvalidCharacter(3, 'R')},
+        attribute_any_group = {"E*Q***", PatternError::InvalidCharacter(2, 'Q')},
+    )]
+    fn new_error(pattern: &str, expected: PatternError) {
+        assert_eq!(Err(expected), CfiPattern::new(pattern));
+    }
+
+    #[yare::parameterized(
+        fixed = {"ESVUFR", "ESVUFR"},
+        wildcards = {"ES??**", "ES****"},
+        set = {"O[PC]*C**", "O[CP]*C**"},
+        single_set = {"[E]S****", "ES****"},
+    )]
+    fn display(pattern: &str, expected: &str) {
+        let pattern = pattern
+            .parse::<CfiPattern>()
+            .expect("Could not parse pattern");
+        assert_eq!(expected, format!("{pattern}"));
+        assert_eq!(Ok(pattern), CfiPattern::try_from(expected));
+    }
+
+    #[test]
+    fn const_pattern() {
+        const BONDS: CfiPattern = match CfiPattern::new("DB****") {
+            Ok(pattern) => pattern,
+            Err(_) => panic!("invalid pattern"),
+        };
+
+        assert!(BONDS.matches_bytes(b"DBFUFR"));
+        assert!(!BONDS.matches_bytes(b"DCFSFR"));
+    }
+}
@@ -0,0 +1,475 @@
+//! Referential instruments category support.
+
+use crate::{NotApplicable, macros};
+
+macros::impl_category! {
+    /// Referential instruments.
+    ///
+    /// Indicators that are used as a reference for other financial instruments.
+    pub enum Referential[b'T'] {
+        /// Currencies.
+        ///
+        /// Medium of exchange of value, defined by reference to the geographical location of the
+        /// monetary authorities responsible for it.
+        Currency(Currency) = b'C', "C";
+
+        /// Commodities.
+        ///
+        /// Bulk goods such as grains, metals and foods, traded on a commodities exchange or on the
+        /// spot market.
+        Commodity(Commodity) = b'T', "T";
+
+        /// Interest rates.
+        ///
+        /// The interest rate represents the amount of interest per currency unit per unit of time.
+        Rate(Rate) = b'R', "R";
+
+        /// Indices.
+        ///
+        /// Indices represent the performance of a specific market or sector, or of a basket of
+        /// instruments, by calculating a weighted value of their constituents.
+        Index(Index) = b'I', "I";
+
+        /// Baskets.
+        ///
+        /// A basket is a group of instruments that have been put together for a specific
+        /// investment purpose.
+        Basket(Basket) = b'B', "B";
+
+        /// Stock dividends.
+        ///
+        /// The dividend paid on a specified equity, used as a reference value.
+        StockDividend(StockDividend) = b'D', "D";
+
+        /// Others (miscellaneous).
+        ///
+        /// Referential instruments that do not fit into any of the above Groups.
+        Other(Other) = b'M', "M";
+    }
+}
+
+macros::impl_group! {
+    /// Currencies.
+    ///
+    /// Medium of exchange of value, defined by reference to the geographical location of the
+    /// monetary authorities responsible for it.
+    pub struct Currency {
+        /// Type.
+        pub kind: CurrencyKind, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Not applicable/undefined.
+        pub unassigned4: NotApplicable, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Commodities.
+    ///
+    /// Bulk goods such as grains, metals and foods, traded on a commodities exchange or on the
+    /// spot market.
+    pub struct Commodity {
+        /// Type.
+        pub kind: CommodityKind, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Not applicable/undefined.
+        pub unassigned4: NotApplicable, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Interest rates.
+    ///
+    /// The interest rate represents the amount of interest per currency unit per unit of time.
+    pub struct Rate {
+        /// Type of interest rate.
+        pub kind: RateKind, 1;
+
+        /// Frequency of calculation.
+        pub frequency: Frequency, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Not applicable/undefined.
+        pub unassigned4: NotApplicable, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Indices.
+    ///
+    /// Indices represent the performance of a specific market or sector, or of a basket of
+    /// instruments, by calculating a weighted value of their constituents.
+    pub struct Index {
+        /// Asset classes.
+        pub asset_class: AssetClass, 1;
+
+        /// Weighting types.
+        pub weighting: Weighting, 2;
+
+        /// Index return types.
+        pub return_kind: IndexReturn, 3;
+
+        /// Not applicable/undefined.
+        pub unassigned4: NotApplicable, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Baskets.
+    ///
+    /// A basket is a group of instruments that have been put together for a specific investment
+    /// purpose.
+    pub struct Basket {
+        /// Composition.
+        pub composition: Composition, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Not applicable/undefined.
+        pub unassigned4: NotApplicable, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Stock dividends.
+    ///
+    /// The dividend paid on a specified equity, used as a reference value.
+    pub struct StockDividend {
+        /// Type of equity.
+        pub equity_kind: EquityKind, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Not applicable/undefined.
+        pub unassigned4: NotApplicable, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Others (miscellaneous).
+    ///
+    /// Referential instruments that do not fit into any of the above Groups.
+    pub struct Other {
+        /// Not applicable/undefined.
+        pub unassigned1: NotApplicable, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Not applicable/undefined.
+        pub unassigned4: NotApplicable, 4;
+    }
+}
+
+macros::impl_attr! {
+    /// Currency type.
+    pub enum CurrencyKind[2] {
+        /// National currency.
+        National = b'N', "N";
+
+        /// Legacy currency.
+        ///
+        /// A currency which has been replaced by another currency and is no longer legal tender.
+        Legacy = b'L', "L";
+
+        /// Bullion coins.
+        Bullion = b'C', "C";
+
+        /// Others (miscellaneous).
+        Other = b'M', "M";
+    }
+}
+
+macros::impl_attr! {
+    /// Commodity type.
+    pub enum CommodityKind[2] {
+        /// Extraction resources (metals, precious metals, coal, oil, gas).
+        Extraction = b'E', "E";
+
+        /// Agriculture (commodities which include forestry, fishing, livestock, grain, dairy,
+        /// corn, cocoa, soybeans, sugar, coffee).
+        Agriculture = b'A', "A";
+
+        /// Industrial products (construction, manufacturing).
+        Industrial = b'I', "I";
+
+        /// Services (transportation, communication, trade).
+        Services = b'S', "S";
+
+        /// Environmental (includes carbon-related, emission reduction, weather).
+        Environmental = b'N', "N";
+
+        /// Polypropylene products (includes plastics).
+        Polypropylene = b'P', "P";
+
+        /// Generated resources (includes electricity, renewable energy, or any power/energy
+        /// delivered through a utility network or provider).
+        Generated = b'H', "H";
+
+        /// Others (miscellaneous).
+        Other = b'M', "M";
+    }
+}
+
+macros::impl_attr! {
+    /// Type of interest rate.
+    pub enum RateKind[2] {
+        /// Nominal.
+        Nominal = b'N', "N";
+
+        /// Variable.
+        Variable = b'V', "V";
+
+        /// Fixed.
+        Fixed = b'F', "F";
+
+        /// Real.
+        Real = b'R', "R";
+
+        /// Others (miscellaneous).
+        Other = b'M', "M";
+    }
+}
+
+macros::impl_attr! {
+    /// Frequency of calculation.
+    pub enum Frequency[3] {
+        /// Daily.
+        Daily = b'D', "D";
+
+        /// Weekly.
+        Weekly = b'W', "W";
+
+        /// Monthly.
+        Monthly = b'N', "N";
+
+        /// Quarterly.
+        Quarterly = b'Q', "Q";
+
+        /// Semi-annually.
+        SemiAnnually = b'S', "S";
+
+        /// Annually.
+        Annually = b'A', "A";
+
+        /// Others (miscellaneous).
+        Other = b'M', "M";
+    }
+}
+
+macros::impl_attr! {
+    /// Asset classes.
+    pub enum AssetClass[2] {
+        /// Equities.
+        Equities = b'E', "E";
+
+        /// Debt.
+        Debt = b'D', "D";
+
+        /// Collective investment vehicles.
+        Civ = b'F', "F";
+
+        /// Real estate.
+        RealEstate = b'R', "R";
+
+        /// Commodities.
+        Commodities = b'T', "T";
+
+        /// Currencies.
+        Currencies = b'C', "C";
+
+        /// Others (miscellaneous).
+        Other = b'M', "M";
+    }
+}
+
+macros::impl_attr! {
+    /// Weighting types.
+    pub enum Weighting[3] {
+        /// Price weighted.
+        Price = b'P', "P";
+
+        /// Capitalization weighted.
+        Capitalization = b'C', "C";
+
+        /// Equal weighted.
+        Equal = b'E', "E";
+
+        /// Modified market capitalization weighted.
+        ModifiedCapitalization = b'F', "F";
+
+        /// Others (miscellaneous).
+        Other = b'M', "M";
+    }
+}
+
+macros::impl_attr! {
+    /// Index return types.
+    pub enum IndexReturn[4] {
+        /// Price return.
+        Price = b'P', "P";
+
+        /// Net total return.
+        NetTotal = b'N', "N";
+
+        /// Gross total return.
+        GrossTotal = b'G', "G";
+
+        /// Others (miscellaneous).
+        Other = b'M', "M";
+    }
+}
+
+macros::impl_attr! {
+    /// Basket composition.
+    pub enum Composition[2] {
+        /// Equities.
+        Equities = b'E', "E";
+
+        /// Debt.
+        Debt = b'D', "D";
+
+        /// Collective investment vehicles.
+        Civ = b'F', "F";
+
+        /// Indices.
+        Indices = b'I', "I";
+
+        /// Commodities.
+        Commodities = b'T', "T";
+
+        /// Currencies.
+        Currencies = b'C', "C";
+
+        /// Others (miscellaneous).
+        Other = b'M', "M";
+    }
+}
+
+macros::impl_attr! {
+    /// Type of equity.
+    pub enum EquityKind[2] {
+        /// Common/ordinary shares.
+        Common = b'S', "S";
+
+        /// Preferred/preference shares.
+        Preferred = b'P', "P";
+
+        /// Common/ordinary convertible shares.
+        CommonConvertible = b'C', "C";
+
+        /// Preferred/preference convertible shares.
+        PreferredConvertible = b'F', "F";
+
+        /// Limited partnership units.
+        LlpUnit = b'L', "L";
+
+        /// Collective investment vehicles.
+        Civ = b'K', "K";
+
+        /// Others (miscellaneous).
+        Other = b'M', "M";
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CFI_LENGTH, Code};
+
+    #[yare::parameterized(
+        currency_undefined = {
+            *b"TCXXXX",
+            Referential::Currency(Currency {
+                kind: CurrencyKind::Undefined,
+                unassigned2: NotApplicable::Undefined,
+                unassigned3: NotApplicable::Undefined,
+                unassigned4: NotApplicable::Undefined,
+            })
+        },
+        commodity = {
+            *b"TTAXXX",
+            Referential::Commodity(Commodity {
+                kind: CommodityKind::Agriculture,
+                unassigned2: NotApplicable::Undefined,
+                unassigned3: NotApplicable::Undefined,
+                unassigned4: NotApplicable::Undefined,
+            })
+        },
+        rate = {
+            *b"TRVQXX",
+            Referential::Rate(Rate {
+                kind: RateKind::Variable,
+                frequency: Frequency::Quarterly,
+                unassigned3: NotApplicable::Undefined,
+                unassigned4: NotApplicable::Undefined,
+            })
+        },
+        index = {
+            *b"TIECGX",
+            Referential::Index(Index {
+                asset_class: AssetClass::Equities,
+                weighting: Weighting::Capitalization,
+                return_kind: IndexReturn::GrossTotal,
+                unassigned4: NotApplicable::Undefined,
+            })
+        },
+        basket = {
+            *b"TBDXXX",
+            Referential::Basket(Basket {
+                composition: Composition::Debt,
+                unassigned2: NotApplicable::Undefined,
+                unassigned3: NotApplicable::Undefined,
+                unassigned4: NotApplicable::Undefined,
+            })
+        },
+        stock_dividend = {
+            *b"TDPXXX",
+            Referential::StockDividend(StockDividend {
+                equity_kind: EquityKind::Preferred,
+                unassigned2: NotApplicable::Undefined,
+                unassigned3: NotApplicable::Undefined,
+                unassigned4: NotApplicable::Undefined,
+            })
+        },
+        other = {
+            *b"TMXXXX",
+            Referential::Other(Other {
+                unassigned1: NotApplicable::Undefined,
+                unassigned2: NotApplicable::Undefined,
+                unassigned3: NotApplicable::Undefined,
+                unassigned4: NotApplicable::Undefined,
+            })
+        },
+    )]
+    fn from_bytes(src: [u8; CFI_LENGTH], expected: Referential) {
+        let referential = Referential::from_bytes(&src).expect("Could not parse code");
+        assert_eq!(expected, referential);
+        assert_eq!(src, referential.to_bytes());
+        assert_eq!(Ok(Code::Referential(expected)), Code::from_bytes(&src));
+    }
+}
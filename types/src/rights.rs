@@ -7,12 +7,12 @@ macros::impl_category! {
     ///
     /// Financial instruments providing the holder with the privilege to subscribe to or receive
     /// specific assets on terms specified.
-    pub enum Right {
+    pub enum Right[b'R'] {
         /// Allotment (bonus) rights.
         ///
         /// Privileges allotted to existing security holders, entitling them to receive new
         /// securities free of charge.
-        Allotment(Allotment) = b'A', "B";
+        Allotment(Allotment) = b'A', "A";
 
         /// Subscription rights.
         ///
@@ -128,7 +128,7 @@ macros::impl_group! {
     pub struct Warrant {
         /// Underlying assets (indicates the type of underlying assets that the warrant holder is
         /// entitled to acquire).
-        pub asset: Assets, 1;
+        pub asset: FutureAsset, 1;
 
         /// Type (indicates whether the warrant is issued by the issuer of the underlying
         /// instrument or by a third party).
@@ -155,8 +155,8 @@ macros::impl_group! {
     /// actually utilize. In contrast to options, factors like volatility have no influence at all
     /// on the price of mini-futures.
     pub struct MiniFuture {
-        /// Underlying assets (indicates the type of underlying assets that the warrant holder is
-        /// entitled to acquire).
+        /// Underlying assets (indicates the type of underlying assets that the mini-future holder
+        /// is entitled to acquire).
         pub asset: FutureAsset, 1;
 
         /// Barrier dependency type (indicates whether the instrument barrier depends on the
@@ -318,7 +318,8 @@ macros::impl_attr! {
 macros::impl_attr! {
     /// Underlying assets.
     ///
-    /// Indicates the type of underlying assets that the warrant holder is entitled to acquire.
+    /// Indicates the type of underlying assets that the holder of a warrant or mini-future is
+    /// entitled to acquire.
     pub enum FutureAsset[2] {
         /// Baskets (the warrant holder is entitled to acquire a package or group of assets).
         Basket = b'B', "B";
@@ -403,3 +404,24 @@ macros::impl_attr! {
         Other = b'M', "M";
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::CFI_LENGTH;
+
+    #[yare::parameterized(
+        allotment = {*b"RAXXXX", Right::is_allotment},
+        subscription = {*b"RSXXXX", Right::is_subscription},
+        purchase = {*b"RPXXXX", Right::is_purchase},
+        warrant = {*b"RWXXXX", Right::is_warrant},
+        mini_future = {*b"RFXXXX", Right::is_mini_future},
+        depository_receipt = {*b"RDXXXX", Right::is_depository_receipt},
+        other = {*b"RMXXXX", Right::is_other},
+    )]
+    fn from_bytes_undefined(src: [u8; CFI_LENGTH], func: fn(&Right) -> bool) {
+        let right = Right::from_bytes(&src).expect("Could not parse undefined attributes");
+        assert!(func(&right));
+        assert_eq!(src, right.to_bytes());
+    }
+}
@@ -0,0 +1,100 @@
+//! Serde support.
+//!
+//! By default, a [`Code`] is serialized as its six-character string. The [`exploded`] module
+//! provides an alternative, structured representation which can be selected per-field.
+
+pub mod exploded;
+
+use crate::{CFI_LENGTH, Code};
+use ::serde::{
+    de::{Deserialize, Deserializer, Error as DeError, Unexpected, Visitor},
+    ser::{Error as _, Serialize, Serializer},
+};
+use core::{
+    fmt::{Formatter, Result as FmtResult},
+    str,
+};
+
+impl Serialize for Code {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes: [u8; CFI_LENGTH] = self.to_bytes();
+        let value = str::from_utf8(&bytes).map_err(S::Error::custom)?;
+        serializer.serialize_str(value)
+    }
+}
+
+impl<'de> Deserialize<'de> for Code {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(CodeVisitor)
+    }
+}
+
+/// A visitor which parses a CFI code from a string or byte buffer.
+struct CodeVisitor;
+
+impl Visitor<'_> for CodeVisitor {
+    type Value = Code;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        formatter.write_str("a six-character ISO 10962 CFI code")
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        Code::from_bytes(value.as_bytes())
+            .map_err(|_error| E::invalid_value(Unexpected::Str(value), &self))
+    }
+
+    fn visit_bytes<E: DeError>(self, value: &[u8]) -> Result<Self::Value, E> {
+        Code::from_bytes(value).map_err(|_error| E::invalid_value(Unexpected::Bytes(value), &self))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::string::ToString as _;
+
+    #[yare::parameterized(
+        equity_common = {"ESVUFR"},
+        debt_bond = {"DBFUFR"},
+        civ_etf = {"CEOIES"},
+        right_warrant = {"RWSTCE"},
+        listed_option_call = {"OCASPS"},
+        swap_rate = {"SRCCSD"},
+        unlisted_option_rate = {"HRCAVC"},
+    )]
+    fn json_roundtrip(src: &str) {
+        let code = Code::from_bytes(src.as_bytes()).expect("Could not parse code");
+        let json = serde_json::to_string(&code).expect("Could not serialize code");
+        assert_eq!(alloc::format!("\"{src}\""), json);
+
+        let actual = serde_json::from_str::<Code>(&json).expect("Could not deserialize code");
+        assert_eq!(code, actual);
+    }
+
+    #[yare::parameterized(
+        short = {"\"ESVUF\""},
+        long = {"\"ESVUFRX\""},
+        category = {"\"ZSVUFR\""},
+        group = {"\"DZFUFR\""},
+        attribute = {"\"DBFUFZ\""},
+    )]
+    fn json_invalid_value(src: &str) {
+        let error = serde_json::from_str::<Code>(src)
+            .expect_err("Invalid code was deserialized")
+            .to_string();
+        assert!(error.starts_with("invalid value"), "{error}");
+    }
+
+    #[test]
+    fn json_invalid_type() {
+        let error = serde_json::from_str::<Code>("123456")
+            .expect_err("Integer was deserialized")
+            .to_string();
+        assert!(error.starts_with("invalid type"), "{error}");
+    }
+}
@@ -0,0 +1,431 @@
+//! Exploded serde representation.
+//!
+//! This module serializes a [`Code`], category, or group as a map containing the category name,
+//! the group name, and the name of each attribute, keyed by the attribute's field name:
+//!
+//! ```
+//! use iso10962_types::Code;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Instrument {
+//!     #[serde(with = "iso10962_types::serde::exploded")]
+//!     cfi: Code,
+//! }
+//!
+//! let instrument = Instrument {
+//!     cfi: "ESVUFR".parse().expect("Invalid CFI code"),
+//! };
+//!
+//! assert_eq!(
+//!     r#"{"cfi":{"category":"Equity","group":"Common","voting_right":"Voting","ownership":"Free","payment_status":"Fully","form":"Registered"}}"#,
+//!     serde_json::to_string(&instrument).expect("Could not serialize instrument"),
+//! );
+//! ```
+
+use crate::{
+    CFI_LENGTH, Code, Result as CfiResult, civ, debt, equities, financing, forwards, futures, misc,
+    options, referential, rights, spot, strategies, swaps,
+};
+use ::serde::{
+    de::{Deserialize, Deserializer, Error as DeError, MapAccess, Visitor},
+    ser::{Error as _, SerializeMap as _, Serializer},
+};
+use core::{
+    fmt::{Formatter, Result as FmtResult},
+    str,
+};
+
+/// The names of every category.
+const CATEGORY_NAMES: &[&str] = &[
+    equities::Equity::NAME,
+    debt::Debt::NAME,
+    civ::Civ::NAME,
+    rights::Right::NAME,
+    options::Listed::NAME,
+    futures::Future::NAME,
+    swaps::Swap::NAME,
+    options::Unlisted::NAME,
+    spot::Spot::NAME,
+    forwards::Forward::NAME,
+    strategies::Strategy::NAME,
+    financing::Financing::NAME,
+    referential::Referential::NAME,
+    misc::Misc::NAME,
+];
+
+/// The number of entries in an exploded map (category, group, and four attributes).
+const ENTRY_COUNT: usize = 6;
+
+/// The maximum length of a key or value in an exploded map, the longest name of any category,
+/// group, field, or attribute.
+const NAME_LENGTH: usize = {
+    let lengths = [
+        equities::Equity::NAME_LENGTH,
+        debt::Debt::NAME_LENGTH,
+        civ::Civ::NAME_LENGTH,
+        rights::Right::NAME_LENGTH,
+        options::Listed::NAME_LENGTH,
+        futures::Future::NAME_LENGTH,
+        swaps::Swap::NAME_LENGTH,
+        options::Unlisted::NAME_LENGTH,
+        spot::Spot::NAME_LENGTH,
+        forwards::Forward::NAME_LENGTH,
+        strategies::Strategy::NAME_LENGTH,
+        financing::Financing::NAME_LENGTH,
+        referential::Referential::NAME_LENGTH,
+        misc::Misc::NAME_LENGTH,
+    ];
+
+    let mut len = longest_name(&["category", "group"]);
+    let mut idx = 0;
+    while idx < lengths.len() {
+        len = longest(len, lengths[idx]);
+        idx += 1;
+    }
+    len
+};
+
+/// The larger of the two given lengths.
+pub(crate) const fn longest(lhs: usize, rhs: usize) -> usize {
+    if lhs > rhs { lhs } else { rhs }
+}
+
+/// The length of the longest of the given names.
+pub(crate) const fn longest_name(names: &[&str]) -> usize {
+    let mut len = 0;
+    let mut idx = 0;
+    while idx < names.len() {
+        len = longest(len, names[idx].len());
+        idx += 1;
+    }
+    len
+}
+
+/// A type which can be serialized using the exploded representation.
+pub trait Exploded: Sized {
+    /// Encode this value as CFI code bytes.
+    fn to_cfi(&self) -> [u8; CFI_LENGTH];
+
+    /// Parse the given CFI code bytes into this value.
+    ///
+    /// # Errors
+    ///
+    /// - A specific error if the bytes do not represent a valid value of this type.
+    fn from_cfi(value: &[u8; CFI_LENGTH]) -> CfiResult<Self>;
+}
+
+impl Exploded for Code {
+    #[inline]
+    fn to_cfi(&self) -> [u8; CFI_LENGTH] {
+        self.to_bytes()
+    }
+
+    #[inline]
+    fn from_cfi(value: &[u8; CFI_LENGTH]) -> CfiResult<Self> {
+        Self::from_bytes(value)
+    }
+}
+
+/// Serialize the given value using the exploded representation.
+///
+/// # Errors
+///
+/// - Any error generated by the serializer.
+#[inline]
+pub fn serialize<T: Exploded, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    let code = Code::from_bytes(&value.to_cfi()).map_err(S::Error::custom)?;
+    let mut map = serializer.serialize_map(Some(ENTRY_COUNT))?;
+
+    match code {
+        Code::Equity(value) => value.serialize_exploded(&mut map)?,
+        Code::Debt(value) => value.serialize_exploded(&mut map)?,
+        Code::Civ(value) => value.serialize_exploded(&mut map)?,
+        Code::Right(value) => value.serialize_exploded(&mut map)?,
+        Code::ListedOption(value) => value.serialize_exploded(&mut map)?,
+        Code::Future(value) => value.serialize_exploded(&mut map)?,
+        Code::Swap(value) => value.serialize_exploded(&mut map)?,
+        Code::UnlistedOption(value) => value.serialize_exploded(&mut map)?,
+        Code::Spot(value) => value.serialize_exploded(&mut map)?,
+        Code::Forward(value) => value.serialize_exploded(&mut map)?,
+        Code::Strategy(value) => value.serialize_exploded(&mut map)?,
+        Code::Financing(value) => value.serialize_exploded(&mut map)?,
+        Code::Referential(value) => value.serialize_exploded(&mut map)?,
+        Code::Misc(value) => value.serialize_exploded(&mut map)?,
+    }
+
+    map.end()
+}
+
+/// Deserialize a value from the exploded representation.
+///
+/// # Errors
+///
+/// - Any error generated by the deserializer.
+/// - An error if an entry is missing, duplicated, or names an unknown variant.
+/// - An error if the resulting code is not valid for the requested type.
+#[inline]
+pub fn deserialize<'de, T: Exploded, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    let entries = deserializer.deserialize_map(EntriesVisitor)?;
+    let mut bytes = [0u8; CFI_LENGTH];
+
+    match entries.name("category")? {
+        equities::Equity::NAME => equities::Equity::deserialize_exploded(&entries, &mut bytes),
+        debt::Debt::NAME => debt::Debt::deserialize_exploded(&entries, &mut bytes),
+        civ::Civ::NAME => civ::Civ::deserialize_exploded(&entries, &mut bytes),
+        rights::Right::NAME => rights::Right::deserialize_exploded(&entries, &mut bytes),
+        options::Listed::NAME => options::Listed::deserialize_exploded(&entries, &mut bytes),
+        futures::Future::NAME => futures::Future::deserialize_exploded(&entries, &mut bytes),
+        swaps::Swap::NAME => swaps::Swap::deserialize_exploded(&entries, &mut bytes),
+        options::Unlisted::NAME => options::Unlisted::deserialize_exploded(&entries, &mut bytes),
+        spot::Spot::NAME => spot::Spot::deserialize_exploded(&entries, &mut bytes),
+        forwards::Forward::NAME => forwards::Forward::deserialize_exploded(&entries, &mut bytes),
+        strategies::Strategy::NAME => {
+            strategies::Strategy::deserialize_exploded(&entries, &mut bytes)
+        }
+        financing::Financing::NAME => {
+            financing::Financing::deserialize_exploded(&entries, &mut bytes)
+        }
+        referential::Referential::NAME => {
+            referential::Referential::deserialize_exploded(&entries, &mut bytes)
+        }
+        misc::Misc::NAME => misc::Misc::deserialize_exploded(&entries, &mut bytes),
+        other => Err(D::Error::unknown_variant(other, CATEGORY_NAMES)),
+    }?;
+
+    T::from_cfi(&bytes).map_err(D::Error::custom)
+}
+
+/// A key or value read from an exploded map, stored without allocating.
+#[derive(Clone, Copy)]
+struct Name {
+    /// The number of bytes used in the buffer.
+    len: usize,
+
+    /// The UTF-8 bytes of the name.
+    buf: [u8; NAME_LENGTH],
+}
+
+impl Name {
+    /// An empty name.
+    const EMPTY: Self = Self {
+        len: 0,
+        buf: [0u8; NAME_LENGTH],
+    };
+
+    /// Get this name as a string.
+    fn as_str(&self) -> &str {
+        str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+}
+
+impl<'de> Deserialize<'de> for Name {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(NameVisitor)
+    }
+}
+
+/// A visitor which copies a string into a [`Name`].
+struct NameVisitor;
+
+impl Visitor<'_> for NameVisitor {
+    type Value = Name;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(formatter, "a string of at most {NAME_LENGTH} bytes")
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        let mut retval = Name::EMPTY;
+        retval
+            .buf
+            .get_mut(..value.len())
+            .ok_or_else(|| E::invalid_length(value.len(), &self))?
+            .copy_from_slice(value.as_bytes());
+        retval.len = value.len();
+        Ok(retval)
+    }
+}
+
+/// The entries read from an exploded map.
+pub(crate) struct Entries {
+    /// The number of entries read.
+    len: usize,
+
+    /// The keys of each entry.
+    keys: [Name; ENTRY_COUNT],
+
+    /// The values of each entry.
+    values: [Name; ENTRY_COUNT],
+}
+
+impl Entries {
+    /// Get the value of the entry with the given key.
+    pub(crate) fn name<E: DeError>(&self, key: &'static str) -> Result<&str, E> {
+        self.keys[..self.len]
+            .iter()
+            .position(|name| name.as_str() == key)
+            .map(|idx| self.values[idx].as_str())
+            .ok_or_else(|| E::missing_field(key))
+    }
+
+    /// Parse the value of the entry with the given key into an attribute.
+    pub(crate) fn attr<T, E: DeError>(
+        &self,
+        key: &'static str,
+        names: &'static [&'static str],
+        from_name: fn(&str) -> Option<T>,
+    ) -> Result<T, E> {
+        let value = self.name(key)?;
+        from_name(value).ok_or_else(|| E::unknown_variant(value, names))
+    }
+}
+
+/// A visitor which reads the entries of an exploded map.
+struct EntriesVisitor;
+
+impl<'de> Visitor<'de> for EntriesVisitor {
+    type Value = Entries;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(formatter, "a map of {ENTRY_COUNT} CFI code names")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut retval = Entries {
+            len: 0,
+            keys: [Name::EMPTY; ENTRY_COUNT],
+            values: [Name::EMPTY; ENTRY_COUNT],
+        };
+
+        while let Some(key) = map.next_key::<Name>()? {
+            if retval.keys[..retval.len]
+                .iter()
+                .any(|name| name.as_str() == key.as_str())
+            {
+                return Err(A::Error::custom(format_args!(
+                    "duplicate field `{}`",
+                    key.as_str()
+                )));
+            }
+
+            if retval.len == ENTRY_COUNT {
+                return Err(A::Error::invalid_length(retval.len + 1, &self));
+            }
+
+            retval.keys[retval.len] = key;
+            retval.values[retval.len] = map.next_value()?;
+            retval.len += 1;
+        }
+
+        Ok(retval)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::{string::ToString as _, vec::Vec};
+
+    /// Serialize the given value as exploded JSON.
+    fn to_json<T: Exploded>(value: &T) -> Vec<u8> {
+        let mut retval = Vec::new();
+        serialize(value, &mut serde_json::Serializer::new(&mut retval))
+            .expect("Could not serialize value");
+        retval
+    }
+
+    /// Deserialize the given exploded JSON.
+    fn from_json<T: Exploded>(json: &str) -> serde_json::Result<T> {
+        deserialize(&mut serde_json::Deserializer::from_str(json))
+    }
+
+    #[yare::parameterized(
+        equity_common = {*b"ESVUFR"},
+        debt_bond = {*b"DBFUFR"},
+        civ_etf = {*b"CEOIES"},
+        right_warrant = {*b"RWSTCE"},
+        listed_option_call = {*b"OCASPS"},
+        swap_rate = {*b"SRCCSD"},
+        unlisted_option_rate = {*b"HRCAVC"},
+    )]
+    fn code_roundtrip(src: [u8; CFI_LENGTH]) {
+        let code = Code::from_bytes(&src).expect("Could not parse code");
+        let json = to_json(&code);
+        let json = str::from_utf8(&json).expect("Invalid UTF-8");
+        assert_eq!(Ok(code), from_json::<Code>(json).map_err(|e| e.to_string()));
+    }
+
+    #[test]
+    fn longest_name() {
+        assert_eq!("RedeemableExchangeableExtendible".len(), NAME_LENGTH);
+
+        let code = Code::from_bytes(b"EPNAFR").expect("Could not parse code");
+        let json = to_json(&code);
+        let json = str::from_utf8(&json).expect("Invalid UTF-8");
+        assert!(json.contains(r#""redemption":"RedeemableExchangeableExtendible""#));
+        assert_eq!(Ok(code), from_json::<Code>(json).map_err(|e| e.to_string()));
+    }
+
+    #[test]
+    fn category_names() {
+        for category in Code::CATEGORIES {
+            let code = Code::iter_all()
+                .find(|code| code.to_bytes()[0] == *category)
+                .expect("Category has no codes");
+            assert!(CATEGORY_NAMES.contains(&code.describe().category.name));
+        }
+        assert_eq!(Code::CATEGORIES.len(), CATEGORY_NAMES.len());
+    }
+
+    #[test]
+    fn code_json() {
+        let code = Code::from_bytes(b"ESVUFR").expect("Could not parse code");
+        assert_eq!(
+            br#"{"category":"Equity","group":"Common","voting_right":"Voting","ownership":"Free","payment_status":"Fully","form":"Registered"}"#
+                .as_slice(),
+            to_json(&code).as_slice()
+        );
+    }
+
+    #[test]
+    fn category_and_group() {
+        let json = r#"{"group":"Bond","category":"Debt","kind":"FixedRate","guarantee":"Unsecured","redemption":"FixedMaturity","form":"Registered"}"#;
+        let category = from_json::<debt::Debt>(json).expect("Could not parse category");
+        assert_eq!(*b"DBFUFR", category.to_bytes());
+        assert_eq!(json.len(), to_json(&category).len());
+
+        let group = from_json::<debt::Bond>(json).expect("Could not parse group");
+        assert_eq!(*b"DBFUFR", group.to_bytes());
+        assert_eq!(to_json(&category), to_json(&group));
+    }
+
+    #[yare::parameterized(
+        unknown_category = {r#"{"category":"Bogus"}"#, "unknown variant `Bogus`"},
+        missing_category = {r#"{"group":"Common"}"#, "missing field `category`"},
+        unknown_group = {r#"{"category":"Equity","group":"Bogus"}"#, "unknown variant `Bogus`"},
+        missing_attr = {r#"{"category":"Equity","group":"Common"}"#, "missing field `voting_right`"},
+        unknown_attr = {
+            r#"{"category":"Equity","group":"Common","voting_right":"Bogus","ownership":"Free","payment_status":"Fully","form":"Registered"}"#,
+            "unknown variant `Bogus`"
+        },
+        duplicate = {r#"{"category":"Equity","category":"Equity"}"#, "duplicate field `category`"},
+        too_many = {
+            r#"{"category":"Equity","group":"Common","voting_right":"Voting","ownership":"Free","payment_status":"Fully","form":"Registered","extra":"Bogus"}"#,
+            "invalid length 7"
+        },
+        too_long = {r#"{"category":"ABCDEFGHIJKLMNOPQRSTUVWXYZABCDEFGHIJ"}"#, "invalid length 36"},
+        wrong_category = {
+            r#"{"category":"Debt","group":"Bond","kind":"FixedRate","guarantee":"Unsecured","redemption":"FixedMaturity","form":"Registered"}"#,
+            "Invalid category 'D'"
+        },
+    )]
+    fn deserialize_error(json: &str, expected: &str) {
+        let error = from_json::<equities::Equity>(json)
+            .expect_err("Invalid value was deserialized")
+            .to_string();
+        assert!(error.starts_with(expected), "{error}");
+    }
+}
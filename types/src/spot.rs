@@ -0,0 +1,154 @@
+//! Spot category support.
+
+use crate::{NotApplicable, macros};
+
+macros::impl_category! {
+    /// Spot.
+    ///
+    /// Contracts conducted on the spot market which are bought and sold for cash with immediate
+    /// delivery based on market convention for the asset.
+    pub enum Spot[b'I'] {
+        /// Foreign exchange.
+        ///
+        /// A foreign exchange spot contract is an agreement between two parties to buy one
+        /// currency against selling another currency at an agreed price for settlement on the
+        /// spot date.
+        Forex(Forex) = b'F', "F";
+
+        /// Commodities.
+        ///
+        /// A commodity spot contract is an agreement between two parties to buy or sell a
+        /// specified commodity at an agreed price for delivery on the spot date.
+        Commodity(Commodity) = b'T', "T";
+    }
+}
+
+macros::impl_group! {
+    /// Foreign exchange.
+    ///
+    /// A foreign exchange spot contract is an agreement between two parties to buy one currency
+    /// against selling another currency at an agreed price for settlement on the spot date.
+    pub struct Forex {
+        /// Not applicable/undefined.
+        pub unassigned1: NotApplicable, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Delivery.
+        pub delivery: Delivery, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Commodities.
+    ///
+    /// A commodity spot contract is an agreement between two parties to buy or sell a specified
+    /// commodity at an agreed price for delivery on the spot date.
+    pub struct Commodity {
+        /// Underlying assets.
+        pub underlying: CommodityUnderlying, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Delivery.
+        pub delivery: Delivery, 4;
+    }
+}
+
+macros::impl_attr! {
+    /// Underlying commodity assets.
+    pub enum CommodityUnderlying[2] {
+        /// Agriculture.
+        ///
+        /// Commodities which include forestry, fishing, livestock, grain, dairy, corn, cocoa,
+        /// soybeans, sugar, coffee.
+        Agriculture = b'A', "A";
+
+        /// Energy.
+        ///
+        /// An energy-related product, including electricity, renewable energy, or any
+        /// power/energy delivered through a utility network or provider; diesel fuel, fuel oil,
+        /// gas oil, gasoline, heating oil, jet fuel, kerosene, natural gas, oil.
+        Energy = b'J', "J";
+
+        /// Metals.
+        ///
+        /// A precious or industrial metal, such as aluminium, copper, gold, lead, nickel,
+        /// platinum, silver, tin, zinc.
+        Metals = b'K', "K";
+
+        /// Environmental.
+        ///
+        /// Includes carbon-related, emission reduction, weather.
+        Environmental = b'N', "N";
+
+        /// Polypropylene products (includes plastics).
+        Polypropylene = b'P', "P";
+
+        /// Fertilizer.
+        ///
+        /// Ammonia, diammonium phosphate (DAP), potash, sulphur, urea, urea and ammonium nitrate
+        /// (UAN).
+        Fertilizer = b'S', "S";
+
+        /// Paper.
+        ///
+        /// Containerboard, newsprint, pulp, recovered paper.
+        Paper = b'T', "T";
+
+        /// Others (miscellaneous).
+        Other = b'M', "M";
+    }
+}
+
+macros::impl_attr! {
+    /// Delivery.
+    pub enum Delivery[5] {
+        /// Physical.
+        ///
+        /// The meeting of a settlement obligation through the receipt or delivery of the actual
+        /// underlying asset.
+        Physical = b'P', "P";
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CFI_LENGTH, Code};
+
+    #[yare::parameterized(
+        forex_undefined = {
+            *b"IFXXXX",
+            Spot::Forex(Forex {
+                unassigned1: NotApplicable::Undefined,
+                unassigned2: NotApplicable::Undefined,
+                unassigned3: NotApplicable::Undefined,
+                delivery: Delivery::Undefined,
+            })
+        },
+        commodity_polypropylene = {
+            *b"ITPXXP",
+            Spot::Commodity(Commodity {
+                underlying: CommodityUnderlying::Polypropylene,
+                unassigned2: NotApplicable::Undefined,
+                unassigned3: NotApplicable::Undefined,
+                delivery: Delivery::Physical,
+            })
+        },
+    )]
+    fn from_bytes(src: [u8; CFI_LENGTH], expected: Spot) {
+        let spot = Spot::from_bytes(&src).expect("Could not parse code");
+        assert_eq!(expected, spot);
+        assert_eq!(src, spot.to_bytes());
+        assert_eq!(Ok(Code::Spot(expected)), Code::from_bytes(&src));
+    }
+}
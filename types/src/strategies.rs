@@ -0,0 +1,267 @@
+//! Strategies category support.
+
+use crate::{NotApplicable, macros};
+
+macros::impl_category! {
+    /// Strategies.
+    ///
+    /// This subclause defines a classification of derivative strategies. Strategies are the
+    /// simultaneous trading of two or more derivative instruments.
+    pub enum Strategy[b'K'] {
+        /// Rates.
+        ///
+        /// Strategies involving rates derivatives, i.e. derivatives whose underlying is an interest
+        /// rate, an interest rate index or an interest rate product.
+        Rate(Rate) = b'R', "R";
+
+        /// Commodities.
+        ///
+        /// Strategies involving commodity derivatives, i.e. derivatives whose underlying is a
+        /// commodity, a commodity index or a basket of commodities.
+        Commodity(Commodity) = b'T', "T";
+
+        /// Equity.
+        ///
+        /// Strategies involving equity derivatives, i.e. derivatives whose underlying is an
+        /// equity-linked instrument such as shares, indices or baskets.
+        Equity(Equity) = b'E', "E";
+
+        /// Credit.
+        ///
+        /// Strategies involving credit derivatives, i.e. derivatives whose underlying is a debt
+        /// product or debt issuer.
+        Credit(Credit) = b'C', "C";
+
+        /// Foreign exchange.
+        ///
+        /// Strategies involving foreign exchange derivatives, i.e. derivatives whose underlying is
+        /// a currency, a currency pair or a basket of currencies.
+        Forex(Forex) = b'F', "F";
+
+        /// Mixed assets.
+        ///
+        /// Strategies involving derivatives from more than one asset class.
+        Mixed(Mixed) = b'Y', "Y";
+
+        /// Others (miscellaneous).
+        ///
+        /// Strategies that do not fit into any of the above Groups.
+        Other(Other) = b'M', "M";
+    }
+}
+
+macros::impl_group! {
+    /// Rates.
+    ///
+    /// Strategies involving rates derivatives, i.e. derivatives whose underlying is an interest
+    /// rate, an interest rate index or an interest rate product.
+    pub struct Rate {
+        /// Not applicable/undefined.
+        pub unassigned1: NotApplicable, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Not applicable/undefined.
+        pub unassigned4: NotApplicable, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Commodities.
+    ///
+    /// Strategies involving commodity derivatives, i.e. derivatives whose underlying is a
+    /// commodity, a commodity index or a basket of commodities.
+    pub struct Commodity {
+        /// Not applicable/undefined.
+        pub unassigned1: NotApplicable, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Not applicable/undefined.
+        pub unassigned4: NotApplicable, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Equity.
+    ///
+    /// Strategies involving equity derivatives, i.e. derivatives whose underlying is an
+    /// equity-linked instrument such as shares, indices or baskets.
+    pub struct Equity {
+        /// Not applicable/undefined.
+        pub unassigned1: NotApplicable, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Not applicable/undefined.
+        pub unassigned4: NotApplicable, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Credit.
+    ///
+    /// Strategies involving credit derivatives, i.e. derivatives whose underlying is a debt product
+    /// or debt issuer.
+    pub struct Credit {
+        /// Not applicable/undefined.
+        pub unassigned1: NotApplicable, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Not applicable/undefined.
+        pub unassigned4: NotApplicable, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Foreign exchange.
+    ///
+    /// Strategies involving foreign exchange derivatives, i.e. derivatives whose underlying is a
+    /// currency, a currency pair or a basket of currencies.
+    pub struct Forex {
+        /// Not applicable/undefined.
+        pub unassigned1: NotApplicable, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Not applicable/undefined.
+        pub unassigned4: NotApplicable, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Mixed assets.
+    ///
+    /// Strategies involving derivatives from more than one asset class.
+    pub struct Mixed {
+        /// Not applicable/undefined.
+        pub unassigned1: NotApplicable, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Not applicable/undefined.
+        pub unassigned4: NotApplicable, 4;
+    }
+}
+
+macros::impl_group! {
+    /// Others (miscellaneous).
+    ///
+    /// Strategies that do not fit into any of the above Groups.
+    pub struct Other {
+        /// Not applicable/undefined.
+        pub unassigned1: NotApplicable, 1;
+
+        /// Not applicable/undefined.
+        pub unassigned2: NotApplicable, 2;
+
+        /// Not applicable/undefined.
+        pub unassigned3: NotApplicable, 3;
+
+        /// Not applicable/undefined.
+        pub unassigned4: NotApplicable, 4;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CFI_LENGTH, Code};
+
+    #[yare::parameterized(
+        rate = {
+            *b"KRXXXX",
+            Strategy::Rate(Rate {
+                unassigned1: NotApplicable::Undefined,
+                unassigned2: NotApplicable::Undefined,
+                unassigned3: NotApplicable::Undefined,
+                unassigned4: NotApplicable::Undefined,
+            })
+        },
+        commodity = {
+            *b"KTXXXX",
+            Strategy::Commodity(Commodity {
+                unassigned1: NotApplicable::Undefined,
+                unassigned2: NotApplicable::Undefined,
+                unassigned3: NotApplicable::Undefined,
+                unassigned4: NotApplicable::Undefined,
+            })
+        },
+        equity = {
+            *b"KEXXXX",
+            Strategy::Equity(Equity {
+                unassigned1: NotApplicable::Undefined,
+                unassigned2: NotApplicable::Undefined,
+                unassigned3: NotApplicable::Undefined,
+                unassigned4: NotApplicable::Undefined,
+            })
+        },
+        credit = {
+            *b"KCXXXX",
+            Strategy::Credit(Credit {
+                unassigned1: NotApplicable::Undefined,
+                unassigned2: NotApplicable::Undefined,
+                unassigned3: NotApplicable::Undefined,
+                unassigned4: NotApplicable::Undefined,
+            })
+        },
+        forex = {
+            *b"KFXXXX",
+            Strategy::Forex(Forex {
+                unassigned1: NotApplicable::Undefined,
+                unassigned2: NotApplicable::Undefined,
+                unassigned3: NotApplicable::Undefined,
+                unassigned4: NotApplicable::Undefined,
+            })
+        },
+        mixed = {
+            *b"KYXXXX",
+            Strategy::Mixed(Mixed {
+                unassigned1: NotApplicable::Undefined,
+                unassigned2: NotApplicable::Undefined,
+                unassigned3: NotApplicable::Undefined,
+                unassigned4: NotApplicable::Undefined,
+            })
+        },
+        other = {
+            *b"KMXXXX",
+            Strategy::Other(Other {
+                unassigned1: NotApplicable::Undefined,
+                unassigned2: NotApplicable::Undefined,
+                unassigned3: NotApplicable::Undefined,
+                unassigned4: NotApplicable::Undefined,
+            })
+        },
+    )]
+    fn from_bytes(src: [u8; CFI_LENGTH], expected: Strategy) {
+        let strategy = Strategy::from_bytes(&src).expect("Could not parse code");
+        assert_eq!(expected, strategy);
+        assert_eq!(src, strategy.to_bytes());
+        assert_eq!(Ok(Code::Strategy(expected)), Code::from_bytes(&src));
+    }
+}
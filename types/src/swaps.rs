@@ -11,7 +11,7 @@ macros::impl_category! {
     /// A swap is an agreement or contract where two counterparties agree to exchange periodic
     /// streams of cash flows with each other. Swaps can be executed with a variety of asset
     /// classes, as listed below.
-    pub enum Swap {
+    pub enum Swap[b'S'] {
         /// Rates.
         ///
         /// A rates swap is a contract in which two counterparties each agree to pay the other cash
@@ -664,3 +664,23 @@ macros::impl_attr! {
         ElectAtSettlement = b'E', "E";
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::CFI_LENGTH;
+
+    #[yare::parameterized(
+        rate = {*b"SRXXXX", Swap::is_rate},
+        commodity = {*b"STXXXX", Swap::is_commodity},
+        equity = {*b"SEXXXX", Swap::is_equity},
+        credit = {*b"SCXXXX", Swap::is_credit},
+        forex = {*b"SFXXXX", Swap::is_forex},
+        other = {*b"SMXXXX", Swap::is_other},
+    )]
+    fn from_bytes_undefined(src: [u8; CFI_LENGTH], func: fn(&Swap) -> bool) {
+        let swap = Swap::from_bytes(&src).expect("Could not parse undefined attributes");
+        assert!(func(&swap));
+        assert_eq!(src, swap.to_bytes());
+    }
+}
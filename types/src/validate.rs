@@ -0,0 +1,209 @@
+//! Semantic validation of codes against the guidelines of the standard.
+//!
+//! Parsing only checks that each character is a legal value for its position. Some values are
+//! further restricted by the standard's guidelines depending on the rest of the code, which is
+//! checked separately by the [`Validate`] trait.
+//!
+//! Only one such guideline is checked: the redemption/conversion of an equity
+//! [`DepositoryReceipt`](crate::equities::DepositoryReceipt) on common/ordinary shares or limited
+//! partnership units must be perpetual. Every other category and group has no violations.
+//!
+//! Guidelines which depend on the terms of the instrument rather than the rest of the code cannot
+//! be checked. In particular, the debt [`Guarantee`](crate::debt::Guarantee) guidelines restrict
+//! `N`, `O`, `Q` and `J` to unsecured securities, `P` to unsecured securities which are neither
+//! senior nor junior, and `U` to securities no other value applies to, none of which is recorded
+//! elsewhere in the code.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use iso10962_types::{
+//!     cfi,
+//!     validate::{Validate as _, Violation},
+//! };
+//!
+//! assert!(cfi!("EDSNFR").validate().is_empty());
+//!
+//! let violations = cfi!("EDSRFR").validate();
+//! assert_eq!(
+//!     Some(&Violation::PerpetualOnly(3, 'R')),
+//!     violations.iter().next(),
+//! );
+//! ```
+
+use crate::{Code, list::FixedList};
+use core::{iter::Flatten, slice::Iter};
+use thiserror::Error as ThisError;
+
+/// The maximum number of violations a single code may have, one per attribute.
+const MAX_VIOLATIONS: usize = 4;
+
+/// An enumeration of the guidelines a parsed code may not follow.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, ThisError)]
+pub enum Violation {
+    /// The redemption/conversion at the given position must be perpetual or undefined for
+    /// depository receipts on common/ordinary shares or limited partnership units.
+    #[error(
+        "Redemption/conversion '{1}' at position '{0}' may not be used for common/ordinary shares or limited partnership units."
+    )]
+    PerpetualOnly(usize, char),
+}
+
+/// A fixed-capacity list of the guidelines a code does not follow.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Violations {
+    /// The violations found.
+    list: FixedList<Violation, MAX_VIOLATIONS>,
+}
+
+impl Violations {
+    /// Create a new, empty list of violations.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            list: FixedList::new(),
+        }
+    }
+
+    /// Append a violation to the list.
+    pub(crate) const fn push(&mut self, violation: Violation) {
+        self.list.push(violation);
+    }
+
+    /// Whether no violations were found.
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// The number of violations found.
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Iterate over the violations found, in code order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &Violation> {
+        self.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Violations {
+    type Item = &'a Violation;
+    type IntoIter = Flatten<Iter<'a, Option<Violation>>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.iter()
+    }
+}
+
+/// A trait implemented by codes, categories, and groups to check the standard's guidelines.
+///
+/// Only guidelines which can be decided from the code alone are checked, see the
+/// [module documentation](self) for those which cannot.
+pub trait Validate {
+    /// Check this value against the guidelines which apply to it.
+    fn validate(&self) -> Violations;
+}
+
+impl Validate for Code {
+    #[inline]
+    fn validate(&self) -> Violations {
+        match self {
+            Self::Equity(value) => value.validate(),
+            Self::Debt(value) => value.validate(),
+            Self::Civ(value) => value.validate(),
+            Self::Right(value) => value.validate(),
+            Self::ListedOption(value) => value.validate(),
+            Self::Future(value) => value.validate(),
+            Self::Swap(value) => value.validate(),
+            Self::UnlistedOption(value) => value.validate(),
+            Self::Spot(value) => value.validate(),
+            Self::Forward(value) => value.validate(),
+            Self::Strategy(value) => value.validate(),
+            Self::Financing(value) => value.validate(),
+            Self::Referential(value) => value.validate(),
+            Self::Misc(value) => value.validate(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CFI_LENGTH, cfi, equities};
+
+    #[yare::parameterized(
+        equity_common = {*b"ESVUFR"},
+        equity_dr_common_perpetual = {*b"EDSNFR"},
+        equity_dr_common_undefined = {*b"EDSXFR"},
+        equity_dr_llp_perpetual = {*b"EDLNFR"},
+        equity_dr_preferred_redeemable = {*b"EDPRFR"},
+        equity_dr_other_convertible = {*b"EDMBFR"},
+        debt_bond_senior = {*b"DBFNFR"},
+        debt_mbs_secured = {*b"DGFSFR"},
+        debt_mbs_senior = {*b"DGFNFR"},
+        debt_abs_government = {*b"DAFTFR"},
+        debt_abs_unsecured = {*b"DAFUFR"},
+        debt_abs_junior = {*b"DAFQFR"},
+        debt_abs_negative_pledge = {*b"DAFPFR"},
+        debt_abs_undefined = {*b"DAFXFR"},
+        debt_dr_bond_senior = {*b"DDBFNF"},
+        debt_dr_mbs_secured = {*b"DDGFSF"},
+        debt_dr_abs_unsecured = {*b"DDAFUF"},
+        future = {*b"FFICSX"},
+    )]
+    fn valid(src: [u8; CFI_LENGTH]) {
+        let code = Code::from_bytes(&src).expect("Could not parse code");
+        let violations = code.validate();
+        assert!(violations.is_empty(), "{violations:?}");
+    }
+
+    #[yare::parameterized(
+        equity_dr_common_redeemable = {*b"EDSRFR", Violation::PerpetualOnly(3, 'R')},
+        equity_dr_common_convertible = {*b"EDSBFR", Violation::PerpetualOnly(3, 'B')},
+        equity_dr_llp_convertible_redeemable = {*b"EDLDFR", Violation::PerpetualOnly(3, 'D')},
+    )]
+    fn invalid(src: [u8; CFI_LENGTH], expected: Violation) {
+        let code = Code::from_bytes(&src).expect("Could not parse code");
+        let violations = code.validate();
+        assert_eq!(1, violations.len());
+        assert_eq!(Some(&expected), violations.iter().next());
+    }
+
+    #[test]
+    fn category_and_group() {
+        let Code::Equity(equity) = cfi!("EDSRFR") else {
+            panic!("Code was not an equity");
+        };
+        let equities::Equity::DepositoryReceipt(receipt) = equity else {
+            panic!("Equity was not a depository receipt");
+        };
+        assert_eq!(equity.validate(), receipt.validate());
+        assert_eq!(1, receipt.validate().len());
+
+        let Code::Debt(debt) = cfi!("DBFNFR") else {
+            panic!("Code was not debt");
+        };
+        assert!(debt.validate().is_empty());
+    }
+
+    #[test]
+    fn push_capacity() {
+        let mut violations = Violations::new();
+        for idx in 0..=MAX_VIOLATIONS {
+            violations.push(Violation::PerpetualOnly(idx, 'R'));
+        }
+
+        assert_eq!(MAX_VIOLATIONS, violations.len());
+        assert_eq!(
+            Some(&Violation::PerpetualOnly(MAX_VIOLATIONS - 1, 'R')),
+            violations.iter().last()
+        );
+    }
+}